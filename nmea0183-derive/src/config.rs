@@ -1,18 +1,23 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Ident, Lifetime, Result};
+use syn::{Error, Ident, Lifetime, Result};
 
 use crate::meta::{MetaAttribute, MetaAttributeType};
 
 #[derive(Clone)]
 pub struct Config {
     pub input_name: Ident,
+    pub output_name: Ident,
     pub selector_name: Ident,
     pub selector_parser: Option<TokenStream>,
     pub selection_error: Option<TokenStream>,
     pub error_type: Ident,
     pub lifetime: Lifetime,
     pub separator: TokenStream,
+    pub collect_errors: bool,
+    pub debug: bool,
+    pub encode: bool,
+    pub inner_checksum: Option<TokenStream>,
 }
 
 impl Config {
@@ -20,6 +25,10 @@ impl Config {
         let mut selector_parser = None;
         let mut separator = quote! { nom::character::complete::char(',') };
         let mut selection_error = None;
+        let mut collect_errors = false;
+        let mut debug = false;
+        let mut encode = false;
+        let mut inner_checksum = None;
 
         for meta in attribute_list {
             match meta.r#type {
@@ -28,18 +37,75 @@ impl Config {
                 MetaAttributeType::SelectionError => {
                     selection_error = Some(meta.arg().unwrap().clone())
                 }
+                MetaAttributeType::CollectErrors => collect_errors = true,
+                MetaAttributeType::Debug => debug = true,
+                MetaAttributeType::Encode => encode = true,
+                MetaAttributeType::InnerChecksum => {
+                    inner_checksum = Some(meta.arg().unwrap().clone())
+                }
                 _ => {}
             }
         }
 
+        if attribute_list
+            .iter()
+            .any(|meta| meta.r#type == MetaAttributeType::Selector)
+            && let Some(meta) = attribute_list
+                .iter()
+                .find(|meta| meta.r#type == MetaAttributeType::SelectorRef)
+        {
+            return Err(Error::new(
+                meta.span(),
+                "nmea0183-derive: Attribute `selector_ref` cannot be used with `selector` attribute.",
+            ));
+        }
+
+        // The selector spans up to the next separator, whatever its length
+        if attribute_list
+            .iter()
+            .any(|meta| meta.r#type == MetaAttributeType::SelectorRef)
+        {
+            selector_parser = Some(quote! {
+                nom::combinator::recognize(nom::multi::many1_count(nom::sequence::preceded(
+                    nom::combinator::not(#separator),
+                    nom::character::complete::anychar,
+                )))
+            });
+        }
+
+        // The encoder always joins the fields with commas, and cannot invert skipped input
+        if encode
+            && let Some(meta) = attribute_list.iter().find(|meta| {
+                matches!(
+                    meta.r#type,
+                    MetaAttributeType::Separator
+                        | MetaAttributeType::SkipAfter
+                        | MetaAttributeType::SkipBefore
+                )
+            })
+        {
+            return Err(Error::new(
+                meta.span(),
+                format!(
+                    "nmea0183-derive: Attribute `{}` cannot be used with `encode` attribute.",
+                    meta.r#type
+                ),
+            ));
+        }
+
         Ok(Self {
             input_name: Ident::new("nmea_input", Span::call_site()),
+            output_name: Ident::new("nmea_output", Span::call_site()),
             selector_name: Ident::new("nmea_selector", Span::call_site()),
             selector_parser,
             selection_error,
             error_type: Ident::new("NmeaError", Span::call_site()),
             lifetime: Lifetime::new("'nmea", Span::call_site()),
             separator,
+            collect_errors,
+            debug,
+            encode,
+            inner_checksum,
         })
     }
 }
@@ -1,6 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, DataEnum, Generics, Ident, Path, Result, parse_quote, spanned::Spanned};
+use syn::{
+    Attribute, DataEnum, Expr, Generics, Ident, Lit, LitStr, Pat, Path, Result, Type,
+    parse::Parser, parse_quote, parse2, spanned::Spanned,
+};
 
 use crate::{
     config::Config,
@@ -27,24 +30,29 @@ impl Enum {
         generics: &Generics,
     ) -> Result<Self> {
         let attributes = meta::parse_top_level_attributes(attributes)?;
-        let config = Config::from_meta_attributes(&attributes)?;
 
-        let has_selector = config.selector_parser.is_some();
-        if !has_selector {
+        if let Some(attribute) = attributes.iter().find(|attribute| {
+            matches!(
+                attribute.r#type,
+                meta::MetaAttributeType::CollectErrors | meta::MetaAttributeType::InnerChecksum
+            )
+        }) {
             return Err(syn::Error::new(
-                name.span(),
-                "nmea0183-derive: Enums must have a `selector` attribute",
+                attribute.span(),
+                format!(
+                    "nmea0183-derive: Enums do not support `{}` attributes; only structs support this feature.",
+                    attribute.r#type
+                ),
             ));
         }
 
-        let separator = attributes
-            .iter()
-            .find(|attr| attr.r#type == meta::MetaAttributeType::Separator);
+        let config = Config::from_meta_attributes(&attributes)?;
 
-        if let Some(attr) = separator {
+        let has_selector = config.selector_parser.is_some();
+        if !has_selector {
             return Err(syn::Error::new(
-                attr.span(),
-                "nmea0183-derive: Enums do not support `separator` attributes yet; this will be implemented in the future.",
+                name.span(),
+                "nmea0183-derive: Enums must have a `selector` or `selector_ref` attribute",
             ));
         }
 
@@ -53,6 +61,7 @@ impl Enum {
             .iter()
             .map(|variant| VariantParser::from_variant(variant, &config))
             .collect::<Result<Vec<_>>>()?;
+        check_one_of_selectors(&config, &variant_parsers)?;
         let (pre_exec, post_exec) = pre_post_exec(&attributes, &config)?;
 
         Ok(Self {
@@ -66,7 +75,6 @@ impl Enum {
     }
 
     pub fn generate_variants(&self) -> Result<(bool, Vec<TokenStream>)> {
-        let enum_name = &self.name;
         let input = &self.config.input_name;
         let mut default_case_handled = false;
         let variant_tokens = self
@@ -77,20 +85,12 @@ impl Enum {
                     default_case_handled = true;
                 }
 
-                let variant_name = &variant_parser.name;
                 let selector = &variant_parser.selector;
 
                 let pre_exec = &variant_parser.pre_exec;
                 let post_exec = &variant_parser.post_exec;
 
-                let r#struct = Struct {
-                    config: self.config.clone(),
-                    name: parse_quote!(#enum_name::#variant_name),
-                    generics: self.generics.clone(),
-                    pre_exec: None,
-                    post_exec: None,
-                    struct_parser: variant_parser.struct_parser.clone(),
-                };
+                let r#struct = self.variant_struct(variant_parser);
 
                 let struct_body = r#struct.generate_parse_body(false).unwrap();
 
@@ -125,6 +125,110 @@ impl Enum {
 
         Ok((default_case_handled, variant_tokens))
     }
+
+    fn variant_struct(&self, variant_parser: &VariantParser) -> Struct {
+        let enum_name = &self.name;
+        let variant_name = &variant_parser.name;
+
+        Struct {
+            config: self.config.clone(),
+            name: parse_quote!(#enum_name::#variant_name),
+            generics: self.generics.clone(),
+            pre_exec: None,
+            post_exec: None,
+            struct_parser: variant_parser.struct_parser.clone(),
+        }
+    }
+}
+
+/// Cross-checks a `one_of("...")` selector parser against the variant selectors.
+///
+/// The check only applies when the selector parser is a call to `one_of` with a string
+/// literal and every variant selector is a char literal, an OR-pattern of char literals
+/// or the `_` catch-all. Any other selector leaves the enum unchecked.
+fn check_one_of_selectors(config: &Config, variant_parsers: &[VariantParser]) -> Result<()> {
+    let Some(set) = config.selector_parser.as_ref().and_then(one_of_set) else {
+        return Ok(());
+    };
+
+    let mut catch_all = false;
+    let mut selectors = Vec::new();
+    for variant_parser in variant_parsers {
+        let Ok(pattern) =
+            Pat::parse_multi_with_leading_vert.parse2(variant_parser.selector.clone())
+        else {
+            return Ok(());
+        };
+
+        let cases = match pattern {
+            Pat::Or(pattern) => pattern.cases.into_iter().collect(),
+            pattern => vec![pattern],
+        };
+        for case in cases {
+            match case {
+                Pat::Lit(syn::ExprLit {
+                    lit: Lit::Char(c), ..
+                }) => selectors.push((c.value(), variant_parser)),
+                Pat::Wild(_) => catch_all = true,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    let set_chars = set.value();
+    for (c, variant_parser) in &selectors {
+        if !set_chars.contains(*c) {
+            return Err(syn::Error::new(
+                variant_parser.selector.span(),
+                format!(
+                    "nmea0183-derive: Selector {:?} of variant `{}` is not in the `one_of` set {:?}",
+                    c, variant_parser.name, set_chars
+                ),
+            ));
+        }
+    }
+
+    if !catch_all
+        && let Some(c) = set_chars
+            .chars()
+            .find(|c| selectors.iter().all(|(selector, _)| selector != c))
+    {
+        return Err(syn::Error::new(
+            set.span(),
+            format!(
+                "nmea0183-derive: Character {:?} of the `one_of` set has no matching variant selector",
+                c
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the string literal of a `one_of("...")` selector parser, if it is one.
+fn one_of_set(selector_parser: &TokenStream) -> Option<LitStr> {
+    let Ok(Expr::Call(call)) = parse2::<Expr>(selector_parser.clone()) else {
+        return None;
+    };
+    let Expr::Path(func) = call.func.as_ref() else {
+        return None;
+    };
+    if func
+        .path
+        .segments
+        .last()
+        .is_none_or(|segment| segment.ident != "one_of")
+        || call.args.len() != 1
+    {
+        return None;
+    }
+
+    match &call.args[0] {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(set), ..
+        }) => Some(set.clone()),
+        _ => None,
+    }
 }
 
 impl Generator for Enum {
@@ -140,6 +244,48 @@ impl Generator for Enum {
         &self.generics
     }
 
+    fn parsed_types(&self) -> Vec<&Type> {
+        self.variant_parsers
+            .iter()
+            .flat_map(|variant_parser| variant_parser.struct_parser.parsed_types())
+            .collect()
+    }
+
+    fn generate_encode_body(&self) -> Result<TokenStream> {
+        let output = &self.config.output_name;
+
+        let arms = self
+            .variant_parsers
+            .iter()
+            .map(|variant_parser| {
+                let selector = parse2::<Lit>(variant_parser.selector.clone()).map_err(|_| {
+                    syn::Error::new(
+                        variant_parser.selector.span(),
+                        "nmea0183-derive: Attribute `encode` requires literal variant selectors",
+                    )
+                })?;
+
+                let r#struct = self.variant_struct(variant_parser);
+                let struct_def = r#struct.struct_def();
+                let fields = r#struct.generate_encode_fields(true);
+
+                Ok(quote! {
+                    #struct_def => {
+                        nmea0183_parser::NmeaEncode::encode_to(&#selector, #output);
+                        #fields
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[allow(unused_variables)]
+            match self {
+                #(#arms)*
+            }
+        })
+    }
+
     fn generate_parse_body(&self, use_nom_parser: bool) -> Result<TokenStream> {
         let (pre_exec, post_exec) = (&self.pre_exec, &self.post_exec);
         let input = &self.config.input_name;
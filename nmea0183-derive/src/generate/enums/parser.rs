@@ -17,7 +17,7 @@ pub struct VariantParser {
 
 impl VariantParser {
     pub fn from_variant(variant: &Variant, config: &Config) -> Result<Self> {
-        let attributes = meta::parse_field_level_attributes(&variant.attrs)?;
+        let attributes = meta::parse_variant_level_attributes(&variant.attrs)?;
 
         let selector = attributes
             .iter()
@@ -28,8 +28,24 @@ impl VariantParser {
                 "nmea0183-derive: Variants must have a `selector` attribute",
             ))?;
 
-        let struct_parser = StructParser::from_fields(&variant.fields, config, true)?;
-        let (pre_exec, post_exec) = pre_post_exec(&attributes, config)?;
+        // A variant-level separator replaces the enum separator for the fields of this variant
+        let mut config = config.clone();
+        if let Some(separator) = attributes
+            .iter()
+            .find(|attr| attr.r#type == meta::MetaAttributeType::Separator)
+        {
+            if config.encode {
+                return Err(Error::new(
+                    separator.span(),
+                    "nmea0183-derive: Attribute `separator` cannot be used with `encode` attribute.",
+                ));
+            }
+
+            config.separator = separator.arg().unwrap().clone();
+        }
+
+        let struct_parser = StructParser::from_fields(&variant.fields, &config, true)?;
+        let (pre_exec, post_exec) = pre_post_exec(&attributes, &config)?;
 
         Ok(Self {
             name: variant.ident.clone(),
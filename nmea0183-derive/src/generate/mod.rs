@@ -1,8 +1,8 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, quote};
 use syn::{
-    Data, DeriveInput, Error, GenericParam, Generics, LifetimeParam, Path, Result, TypeParam,
-    WhereClause, parse_quote,
+    Data, DeriveInput, Error, File, GenericParam, Generics, Ident, LifetimeParam, Path, Result,
+    Type, TypeParam, WhereClause, parse_quote, parse2,
 };
 
 use crate::{
@@ -39,8 +39,51 @@ trait Generator {
     fn name(&self) -> &Path;
     fn config(&self) -> &Config;
     fn generics(&self) -> &Generics;
+    fn parsed_types(&self) -> Vec<&Type>;
     fn generate_parse_body(&self, use_nom_parser: bool) -> Result<TokenStream>;
 
+    fn generate_encode_body(&self) -> Result<TokenStream>;
+
+    fn generate_inherent_impl(&self) -> Result<Option<TokenStream>> {
+        Ok(None)
+    }
+
+    fn generate_encode_impl(&self) -> Result<Option<TokenStream>> {
+        if !self.config().encode {
+            return Ok(None);
+        }
+
+        let name = self.name();
+        let output = &self.config().output_name;
+        let body = self.generate_encode_body()?;
+        let generics = self.generics();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let mut encode_where: WhereClause = if where_clause.is_some() {
+            parse_quote!(#where_clause)
+        } else {
+            parse_quote!(where)
+        };
+
+        // Make sure generic parameters implement NmeaEncode
+        for param in generics.type_params() {
+            let param = &param.ident;
+            encode_where
+                .predicates
+                .push(parse_quote!(#param: nmea0183_parser::NmeaEncode));
+        }
+
+        let encode_tokens = quote! {
+            impl #impl_generics nmea0183_parser::NmeaEncode for #name #ty_generics #encode_where {
+                fn encode_to(&self, #output: &mut nmea0183_parser::__private::String) {
+                    #body
+                }
+            }
+        };
+
+        Ok(Some(encode_tokens))
+    }
+
     fn generate_parse_decl(&self) -> TokenStream {
         let input = &self.config().input_name;
         let error_type = &self.config().error_type;
@@ -70,6 +113,8 @@ trait Generator {
         let error_type = &self.config().error_type;
         let nmea_lifetime = &self.config().lifetime;
         let parse_tokens = self.generate_parse()?;
+        let inherent_tokens = self.generate_inherent_impl()?;
+        let encode_tokens = self.generate_encode_impl()?;
         let generics = self.generics();
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -110,6 +155,27 @@ trait Generator {
             );
         }
 
+        // Composite field types such as `[T; N]` may need more than `T: NmeaParse`, so make
+        // sure every parsed field type using a generic parameter implements NmeaParse as well
+        let params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+        let mut bounded_types = vec![];
+        for ty in self.parsed_types() {
+            let is_param = matches!(ty, Type::Path(path) if path.qself.is_none()
+                && path.path.get_ident().is_some_and(|ident| params.contains(&ident)));
+            let key = ty.to_token_stream().to_string();
+            if is_param
+                || !uses_type_params(ty.to_token_stream(), &params)
+                || bounded_types.contains(&key)
+            {
+                continue;
+            }
+
+            impl_where.predicates.push(
+                parse_quote!(#ty: nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type>),
+            );
+            bounded_types.push(key);
+        }
+
         // // Push nmea input type to the where clause
         // impl_where
         //     .predicates
@@ -125,12 +191,25 @@ trait Generator {
             impl #impl_generics nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type> for #name #ty_generics #impl_where {
                 #parse_tokens
             }
+
+            #inherent_tokens
+
+            #encode_tokens
         };
 
         Ok(impl_tokens)
     }
 }
 
+/// Returns `true` if the tokens mention one of the given type parameters
+fn uses_type_params(tokens: TokenStream, params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.contains(&&ident),
+        TokenTree::Group(group) => uses_type_params(group.stream(), params),
+        _ => false,
+    })
+}
+
 pub fn get_error_if(cond: &TokenStream, config: &Config) -> TokenStream {
     let input = &config.input_name;
     quote! {
@@ -216,5 +295,55 @@ pub fn generate_nmea_parse_impl(input: &DeriveInput) -> Result<TokenStream> {
         }
     };
 
-    generator.generate_impl()
+    let impl_tokens = generator.generate_impl()?;
+
+    if generator.config().debug {
+        eprintln!("{}", debug_output(&input.ident, &impl_tokens));
+    }
+
+    Ok(impl_tokens)
+}
+
+/// Formats the generated implementation printed by the `debug` attribute.
+fn debug_output(name: &Ident, impl_tokens: &TokenStream) -> String {
+    let generated = parse2::<File>(impl_tokens.clone()).map_or_else(
+        |_| impl_tokens.to_string(),
+        |file| prettyplease::unparse(&file),
+    );
+
+    format!("nmea0183-derive: Generated implementation for `{name}`:\n{generated}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_output() {
+        let plain: DeriveInput = parse_quote! {
+            struct Data {
+                #[nmea(map(|v: u32| v as f64), parse_as(u32))]
+                value: f64,
+                mode: Option<char>,
+            }
+        };
+        let debugged: DeriveInput = parse_quote! {
+            #[nmea(debug)]
+            struct Data {
+                #[nmea(map(|v: u32| v as f64), parse_as(u32))]
+                value: f64,
+                mode: Option<char>,
+            }
+        };
+
+        // The debug attribute only prints the generated code, the implementation is unchanged
+        let plain = generate_nmea_parse_impl(&plain).unwrap();
+        let debugged = generate_nmea_parse_impl(&debugged).unwrap();
+        assert_eq!(debugged.to_string(), plain.to_string());
+
+        let output = debug_output(&parse_quote!(Data), &debugged);
+        assert!(output.starts_with("nmea0183-derive: Generated implementation for `Data`:\n"));
+        assert!(output.contains("nmea0183_parser::NmeaParse<&'nmea str, NmeaError> for Data"));
+        assert!(output.contains("fn parse("));
+    }
 }
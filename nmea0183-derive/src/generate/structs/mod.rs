@@ -1,6 +1,6 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Attribute, DataStruct, Generics, Ident, Path, Result, parse_quote};
+use syn::{Attribute, DataStruct, Generics, Ident, Path, Result, Type, WhereClause, parse_quote};
 
 use crate::{
     config::Config,
@@ -20,6 +20,58 @@ pub struct Struct {
 }
 
 impl Struct {
+    /// Returns the struct expression of the fields variables, also usable as a pattern
+    pub fn struct_def(&self) -> TokenStream {
+        let name = &self.name;
+        let variable_name = self
+            .struct_parser
+            .parsers
+            .iter()
+            .map(|field_parser| Ident::new(&field_parser.variable_name, Span::call_site()));
+
+        match (self.struct_parser.empty, self.struct_parser.unnamed) {
+            (true, _) => {
+                // If the struct is empty, we just return an empty struct
+                quote! { #name }
+            }
+            (_, true) => {
+                // If the struct is unnamed, we create a tuple struct
+                quote! { #name(#(#variable_name),*) }
+            }
+            (_, false) => {
+                // If the struct is named, we create a named struct
+                quote! { #name { #(#variable_name),* } }
+            }
+        }
+    }
+
+    /// Encodes the fields variables, each preceded by a comma except for the first one,
+    /// unless `preceded` is set
+    pub fn generate_encode_fields(&self, preceded: bool) -> TokenStream {
+        let output = &self.config.output_name;
+        let mut first_field = !preceded;
+
+        let mut tokens = TokenStream::new();
+        for field_parser in &self.struct_parser.parsers {
+            // Ignored and peeked fields do not consume any input, so there is nothing to write
+            if field_parser.ignore || field_parser.peek {
+                continue;
+            }
+
+            let variable_name = Ident::new(&field_parser.variable_name, Span::call_site());
+            if !first_field {
+                tokens.extend(quote! { #output.push(','); });
+            }
+            first_field = false;
+
+            tokens.extend(quote! {
+                nmea0183_parser::NmeaEncode::encode_to(#variable_name, #output);
+            });
+        }
+
+        tokens
+    }
+
     pub fn from_datastruct(
         name: &Ident,
         datastruct: &DataStruct,
@@ -30,10 +82,13 @@ impl Struct {
 
         for attribute in &attributes {
             match attribute.r#type {
-                meta::MetaAttributeType::Selector => {
+                meta::MetaAttributeType::Selector | meta::MetaAttributeType::SelectorRef => {
                     return Err(syn::Error::new(
                         attribute.span(),
-                        "nmea0183-derive: Structs does not support `selector` attributes; only enums support this feature.",
+                        format!(
+                            "nmea0183-derive: Structs does not support `{}` attributes; only enums support this feature.",
+                            attribute.r#type
+                        ),
                     ));
                 }
                 meta::MetaAttributeType::SelectionError => {
@@ -42,18 +97,19 @@ impl Struct {
                         "nmea0183-derive: Structs do not support `selection_error` attributes; only enums support this feature.",
                     ));
                 }
-                meta::MetaAttributeType::Separator => {
-                    return Err(syn::Error::new(
-                        attribute.span(),
-                        "nmea0183-derive: Structs do not support `separator` attributes yet; this will be implemented in the future.",
-                    ));
-                }
                 _ => {}
             }
         }
 
         let config = Config::from_meta_attributes(&attributes)?;
         let struct_parser = StructParser::from_fields(&datastruct.fields, &config, false)?;
+
+        if config.inner_checksum.is_some() && struct_parser.parsers.is_empty() {
+            return Err(syn::Error::new(
+                name.span(),
+                "nmea0183-derive: Attribute `inner_checksum` requires at least one field to hold the checksum.",
+            ));
+        }
         let (pre_exec, post_exec) = pre_post_exec(&attributes, &config)?;
 
         Ok(Self {
@@ -80,8 +136,11 @@ impl Generator for Struct {
         &self.generics
     }
 
+    fn parsed_types(&self) -> Vec<&Type> {
+        self.struct_parser.parsed_types().collect()
+    }
+
     fn generate_parse_body(&self, use_nom_parser: bool) -> Result<TokenStream> {
-        let name = &self.name;
         let (pre_exec, post_exec) = (&self.pre_exec, &self.post_exec);
         let input = &self.config.input_name;
 
@@ -97,33 +156,50 @@ impl Generator for Struct {
             })
             .unzip();
 
-        let (field_pre_exec, field_post_exec): (Vec<_>, Vec<_>) = self
+        let (mut field_pre_exec, mut field_post_exec): (Vec<_>, Vec<_>) = self
             .struct_parser
             .parsers
             .iter()
             .map(|field_parser| {
                 (
-                    field_parser.pre_exec.as_ref(),
-                    field_parser.post_exec.as_ref(),
+                    field_parser.pre_exec.clone().unwrap_or_default(),
+                    field_parser.post_exec.clone().unwrap_or_default(),
                 )
             })
             .unzip();
 
-        let struct_def = match (self.struct_parser.empty, self.struct_parser.unnamed) {
-            (true, _) => {
-                // If the struct is empty, we just return an empty struct
-                quote! { #name }
-            }
-            (_, true) => {
-                // If the struct is unnamed, we create a tuple struct
-                quote! { #name(#(#variable_name),*) }
-            }
-            (_, false) => {
-                // If the struct is named, we create a named struct
-                quote! { #name { #(#variable_name),* } }
-            }
+        // The last field holds the inner checksum of the content preceding it
+        let inner_checksum_start = if let Some(algorithm) = &self.config.inner_checksum
+            && let Some(last) = variable_name.len().checked_sub(1)
+        {
+            let checksum = &variable_name[last];
+            let error = quote! {
+                nom::Err::Error(nom::error::make_error(
+                    nmea_inner_checksum_input,
+                    nom::error::ErrorKind::Verify,
+                ))
+            };
+
+            field_pre_exec[last].extend(quote! {
+                let nmea_inner_checksum_input = #input;
+                let nmea_inner_checksum_content = &nmea_inner_checksum_start
+                    [..nmea_inner_checksum_start.len() - #input.len()];
+            });
+            let post_exec = std::mem::take(&mut field_post_exec[last]);
+            field_post_exec[last] = quote! {
+                if (#algorithm)(nmea_inner_checksum_content) != #checksum {
+                    return Err(#error);
+                }
+                #post_exec
+            };
+
+            Some(quote! { let nmea_inner_checksum_start = #input; })
+        } else {
+            None
         };
 
+        let struct_def = self.struct_def();
+
         let use_nom_parser = if use_nom_parser {
             quote! { use nom::Parser; }
         } else {
@@ -133,6 +209,7 @@ impl Generator for Struct {
         let body = quote! {
             #use_nom_parser
             #pre_exec
+            #inner_checksum_start
             #(#field_pre_exec let (#input, #variable_name) = #parser.parse(#input)?; #field_post_exec)*
             let struct_def = #struct_def;
             #post_exec
@@ -143,4 +220,120 @@ impl Generator for Struct {
 
         // todo!("Implement generate_parse_body for Struct");
     }
+
+    fn generate_encode_body(&self) -> Result<TokenStream> {
+        let struct_def = self.struct_def();
+        let fields = self.generate_encode_fields(false);
+
+        Ok(quote! {
+            #[allow(unused_variables)]
+            let #struct_def = self;
+            #fields
+        })
+    }
+
+    fn generate_inherent_impl(&self) -> Result<Option<TokenStream>> {
+        if !self.config.collect_errors {
+            return Ok(None);
+        }
+
+        let name = &self.name;
+        let (pre_exec, post_exec) = (&self.pre_exec, &self.post_exec);
+        let input = &self.config.input_name;
+        let error_type = &self.config.error_type;
+        let nmea_lifetime = &self.config.lifetime;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let mut fields = Vec::with_capacity(self.struct_parser.parsers.len());
+        for field_parser in &self.struct_parser.parsers {
+            let variable_name = Ident::new(&field_parser.variable_name, Span::call_site());
+            let field_name = &field_parser.field_name;
+            let parser = &field_parser.parser;
+            let (field_pre_exec, field_post_exec) =
+                (&field_parser.pre_exec, &field_parser.post_exec);
+
+            // On failure, skip the field up to the next separator (or the end of the input),
+            // unless the field is peeked, in which case nothing was meant to be consumed.
+            let recover = if field_parser.peek {
+                quote! { #input }
+            } else {
+                let separator = &self.config.separator;
+                let skip = quote! {
+                    nom::combinator::recognize(nom::multi::many_till(
+                        nom::character::complete::anychar,
+                        nom::combinator::peek(nom::branch::alt((
+                            nom::combinator::value((), #separator),
+                            nom::combinator::value((), nom::combinator::eof),
+                        ))),
+                    ))
+                };
+                let skip = if let Some(separator) = &field_parser.separator {
+                    quote! { (nom::combinator::opt(#separator), #skip) }
+                } else {
+                    skip
+                };
+
+                quote! {{
+                    let recovered: nmea0183_parser::IResult<&#nmea_lifetime str, _, #error_type> =
+                        #skip.parse(#input);
+                    recovered?.0
+                }}
+            };
+
+            fields.push(quote! {
+                #field_pre_exec
+                let (#input, #variable_name) = match #parser.parse(#input) {
+                    Ok(result) => result,
+                    Err(error) => {
+                        nmea_errors.push((#field_name, error));
+                        (#recover, Default::default())
+                    }
+                };
+                #field_post_exec
+            });
+        }
+
+        let struct_def = self.struct_def();
+
+        let mut parse_where: WhereClause = parse_quote!(where);
+        for param in self.generics.type_params() {
+            let param = &param.ident;
+            parse_where.predicates.push(
+                parse_quote!(#param: nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type>),
+            );
+        }
+        parse_where
+            .predicates
+            .push(parse_quote!(#error_type: nom::error::ParseError<&#nmea_lifetime str>));
+
+        let tokens = quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parses every field, substituting `Default::default()` for the fields that
+                /// fail to parse, and returns the errors of those fields along with their names.
+                #[allow(clippy::type_complexity)]
+                pub fn parse_collecting_errors<#nmea_lifetime, #error_type>(
+                    #input: &#nmea_lifetime str,
+                ) -> nmea0183_parser::IResult<
+                    &#nmea_lifetime str,
+                    (
+                        Self,
+                        nmea0183_parser::__private::Vec<(&'static str, nom::Err<nmea0183_parser::Error<&#nmea_lifetime str, #error_type>>)>,
+                    ),
+                    #error_type,
+                >
+                #parse_where
+                {
+                    use nom::Parser;
+                    let mut nmea_errors = nmea0183_parser::__private::Vec::new();
+                    #pre_exec
+                    #(#fields)*
+                    let struct_def = #struct_def;
+                    #post_exec
+                    Ok((#input, (struct_def, nmea_errors)))
+                }
+            }
+        };
+
+        Ok(Some(tokens))
+    }
 }
@@ -1,6 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
-use syn::{Error, Fields, PathArguments, Result, Type, TypePath, parse2, spanned::Spanned};
+use syn::{
+    Error, ExprRange, Fields, GenericArgument, PathArguments, RangeLimits, Result, Type, TypePath,
+    parse2, spanned::Spanned,
+};
 
 use crate::{
     config::Config,
@@ -12,6 +15,11 @@ use crate::{
 #[derive(Clone)]
 pub struct FieldParser {
     pub variable_name: String,
+    pub field_name: String,
+    pub separator: Option<TokenStream>,
+    pub ignore: bool,
+    pub peek: bool,
+    pub parsed_type: Option<Type>,
     pub parser: Parser,
     pub pre_exec: Option<TokenStream>,
     pub post_exec: Option<TokenStream>,
@@ -50,20 +58,63 @@ impl StructParser {
                 || format!("_nmea_unnamed_{index}"),
                 |ident| ident.to_string(),
             );
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), |ident| ident.to_string());
             let attributes = meta::parse_field_level_attributes(&field.attrs)?;
 
+            // Only attributes that do not change how the field is written can be encoded
+            if config.encode
+                && let Some(attribute) = attributes.iter().find(|attribute| {
+                    !matches!(
+                        attribute.r#type,
+                        MetaAttributeType::Ignore
+                            | MetaAttributeType::Peek
+                            | MetaAttributeType::PreExec
+                            | MetaAttributeType::PostExec
+                            | MetaAttributeType::Verify
+                    )
+                })
+            {
+                return Err(Error::new(
+                    attribute.span(),
+                    format!(
+                        "nmea0183-derive: Attribute `{}` cannot be used with `encode` attribute.",
+                        attribute.r#type
+                    ),
+                ));
+            }
+
             let mut ignore = false;
+            let mut peek = false;
             for attribute in &attributes {
-                if attribute.r#type == MetaAttributeType::Ignore {
-                    ignore = true;
+                match attribute.r#type {
+                    MetaAttributeType::Ignore => ignore = true,
+                    MetaAttributeType::Peek => peek = true,
+                    MetaAttributeType::FromSelector => {
+                        // Only the variants of an enum have a selector
+                        if config.selector_parser.is_none() {
+                            return Err(Error::new(
+                                attribute.span(),
+                                "nmea0183-derive: Attribute `from_selector` can only be used on enum variant fields.",
+                            ));
+                        }
+
+                        // The field takes the selector value, it does not consume any input
+                        ignore = true;
+                    }
+                    _ => {}
                 }
             }
 
-            let separator = Some(separator).filter(|_| !first_field && !ignore);
-            let parser = Self::get_parser(&field.ty, &attributes, separator.cloned())?;
-            let parser = parser.as_nmeaparse(error_type, nmea_lifetime);
+            let separator = Some(separator).filter(|_| !first_field && !ignore).cloned();
+            let parser = Self::get_parser(&field.ty, &attributes, separator.clone(), config)?;
+            let parsed_type = parser.parsed_type().cloned();
+            let parser = parser.into_nmeaparse(error_type, nmea_lifetime);
 
-            if first_field && !ignore {
+            // Peeked fields do not consume the input, so the next field is still the first one
+            if first_field && !ignore && !peek {
                 first_field = false;
             }
 
@@ -71,6 +122,11 @@ impl StructParser {
 
             parsers.push(FieldParser {
                 variable_name,
+                field_name,
+                separator,
+                ignore,
+                peek,
+                parsed_type,
                 parser,
                 pre_exec,
                 post_exec,
@@ -84,11 +140,20 @@ impl StructParser {
         })
     }
 
+    /// Returns the types of the fields parsed with their own `NmeaParse` implementation
+    pub fn parsed_types(&self) -> impl Iterator<Item = &Type> {
+        self.parsers
+            .iter()
+            .filter_map(|field_parser| field_parser.parsed_type.as_ref())
+    }
+
     fn get_parser(
         ty: &Type,
         attributes: &[MetaAttribute],
         separator: Option<TokenStream>,
+        config: &Config,
     ) -> Result<Parser> {
+        let input = &config.input_name;
         let mut attributes = attributes;
         while let Some((attribute, rest)) = attributes.split_first() {
             match attribute.r#type {
@@ -101,10 +166,19 @@ impl StructParser {
                     };
                     return Ok(Parser::Raw(parser));
                 }
+                MetaAttributeType::Peek => {
+                    let parser = attribute.arg().unwrap();
+                    let parser = if let Some(separator) = &separator {
+                        quote! { nom::combinator::peek(nom::sequence::preceded(#separator, #parser)) }
+                    } else {
+                        quote! { nom::combinator::peek(#parser) }
+                    };
+                    return Ok(Parser::Raw(parser));
+                }
                 MetaAttributeType::ParseAs => {
                     let parse_as = attribute.arg().unwrap();
                     let parse_as_type = parse2::<Type>(parse_as.clone())?;
-                    let parser = Self::get_parser(&parse_as_type, rest, separator)?;
+                    let parser = Self::get_parser(&parse_as_type, rest, separator, config)?;
                     return Ok(parser);
                 }
                 MetaAttributeType::Ignore => {
@@ -112,28 +186,127 @@ impl StructParser {
                     let parser = quote! { nom::combinator::success(#default) };
                     return Ok(Parser::Raw(parser));
                 }
+                MetaAttributeType::FromSelector => {
+                    let selector = &config.selector_name;
+                    let parser = quote! { nom::combinator::success(#selector.clone()) };
+                    return Ok(Parser::Raw(parser));
+                }
                 MetaAttributeType::Cond => {
                     let option = Self::get_innermost_type_parser(ty, "Option", "cond")?;
                     let option_type = parse2::<Type>(option)?;
-                    let parser = Self::get_parser(&option_type, rest, separator)?;
+                    let parser = Self::get_parser(&option_type, rest, separator, config)?;
                     let condition = attribute.arg().unwrap();
                     return Ok(Parser::Cond {
                         parser: Box::new(parser),
                         condition: condition.clone(),
                     });
                 }
+                MetaAttributeType::IfRemainingFields => {
+                    if !Self::is_option(ty) {
+                        return Err(Error::new(
+                            ty.span(),
+                            "nmea0183-derive: Unexpected type for attribute `if_remaining_fields`. Expected `Option`.",
+                        ));
+                    }
+
+                    // The input starts with the separator preceding the field, so that each of the
+                    // remaining fields is preceded by one separator
+                    if separator.is_none() {
+                        return Err(Error::new(
+                            attribute.span(),
+                            "nmea0183-derive: Attribute `if_remaining_fields` cannot be used on the first field.",
+                        ));
+                    }
+
+                    // The remaining fields are counted by trying the configured separator at
+                    // every position of the input
+                    let field_separator = &config.separator;
+                    let error_type = &config.error_type;
+                    let nmea_lifetime = &config.lifetime;
+                    let count = attribute.arg().unwrap();
+                    let parser = Self::get_parser(ty, rest, separator, config)?;
+                    let parser = Parser::Cond {
+                        parser: Box::new(parser),
+                        condition: quote! {
+                            #input
+                                .char_indices()
+                                .filter(|&(index, _)| {
+                                    let result: nmea0183_parser::IResult<&#nmea_lifetime str, _, #error_type> =
+                                        nom::Parser::parse(&mut #field_separator, &#input[index..]);
+                                    result.is_ok()
+                                })
+                                .count()
+                                >= (#count) as usize
+                        },
+                    };
+
+                    // A present but empty field is `None` as well
+                    return Ok(Parser::Map {
+                        parser: Box::new(parser),
+                        map: quote! { Option::flatten },
+                    });
+                }
+                MetaAttributeType::Count => {
+                    if separator.is_none() {
+                        return Err(Error::new(
+                            attribute.span(),
+                            "nmea0183-derive: Attribute `count` cannot be used on the first field.",
+                        ));
+                    }
+
+                    let element = Self::get_vec_element_type(ty)?;
+                    let parser = Self::get_parser(&element, rest, separator, config)?;
+                    let count = attribute.arg().unwrap();
+                    return Ok(Parser::Count {
+                        parser: Box::new(parser),
+                        count: count.clone(),
+                    });
+                }
                 MetaAttributeType::Into => {
-                    let parser = Self::get_parser(ty, rest, separator)?;
+                    let parser = Self::get_parser(ty, rest, separator, config)?;
                     return Ok(Parser::Into(Box::new(parser)));
                 }
                 MetaAttributeType::Map => {
                     let map = attribute.arg().unwrap();
-                    let parser = Self::get_parser(ty, rest, separator)?;
+                    let parser = Self::get_parser(ty, rest, separator, config)?;
                     return Ok(Parser::Map {
                         parser: Box::new(parser),
                         map: map.clone(),
                     });
                 }
+                MetaAttributeType::Bits => {
+                    let map = Self::get_bits_map(attribute)?;
+                    let parser = Self::get_parser(ty, rest, separator, config)?;
+                    return Ok(Parser::Map {
+                        parser: Box::new(parser),
+                        map,
+                    });
+                }
+                MetaAttributeType::Width => {
+                    // The separator precedes the fixed-width field, it is not part of it
+                    let width = attribute.arg().unwrap();
+                    let parser = Self::get_parser(ty, rest, None, config)?;
+                    let parser = quote! {
+                        nom::combinator::map_parser(
+                            nom::bytes::complete::take((#width) as usize),
+                            #parser,
+                        )
+                    };
+                    let parser = if let Some(separator) = &separator {
+                        quote! { nom::sequence::preceded(#separator, #parser) }
+                    } else {
+                        parser
+                    };
+                    return Ok(Parser::Raw(parser));
+                }
+                MetaAttributeType::Verify => {
+                    let verify = attribute.arg().unwrap();
+                    let parser = Self::get_parser(ty, rest, separator, config)?;
+                    return Ok(Parser::Verify {
+                        parser: Box::new(parser),
+                        verify: verify.clone(),
+                    });
+                }
                 _ => {}
             }
 
@@ -146,17 +319,59 @@ impl StructParser {
         })
     }
 
+    /// Generates the `map` closure extracting the bits in the range of a `bits` attribute,
+    /// shifted down to bit 0 and cast to the type of the field.
+    fn get_bits_map(attribute: &MetaAttribute) -> Result<TokenStream> {
+        let range = parse2::<ExprRange>(attribute.arg().unwrap().clone())?;
+        let (Some(start), Some(end)) = (&range.start, &range.end) else {
+            return Err(Error::new(
+                attribute.span(),
+                "nmea0183-derive: Attribute `bits` expects a bounded range, such as `0..3` or `0..=2`.",
+            ));
+        };
+
+        let width = match range.limits {
+            RangeLimits::HalfOpen(_) => quote! { ((#end) - (#start)) },
+            RangeLimits::Closed(_) => quote! { ((#end) - (#start) + 1) },
+        };
+
+        // The mask is shifted in two steps, so a range covering the whole type never
+        // shifts by the full width of the type
+        Ok(quote! { |value| ((value >> (#start)) & !(!0 << (#width - 1) << 1)) as _ })
+    }
+
+    fn get_vec_element_type(ty: &Type) -> Result<Type> {
+        if let Type::Path(TypePath { path, .. }) = ty
+            && let Some(segment) = path.segments.last()
+            && segment.ident == "Vec"
+            && let PathArguments::AngleBracketed(ref args) = segment.arguments
+            && let [GenericArgument::Type(element)] = args.args.iter().collect::<Vec<_>>()[..]
+        {
+            return Ok(element.clone());
+        }
+
+        Err(Error::new(
+            ty.span(),
+            "nmea0183-derive: Unexpected type for attribute `count`. Expected `Vec<T>`.",
+        ))
+    }
+
+    fn is_option(ty: &Type) -> bool {
+        matches!(ty, Type::Path(TypePath { path, .. })
+            if path.segments.last().is_some_and(|segment| segment.ident == "Option"))
+    }
+
     fn get_innermost_type_parser(ty: &Type, expected: &str, attr: &str) -> Result<TokenStream> {
-        if let Type::Path(TypePath { path, .. }) = ty {
-            if let Some(segment) = path.segments.last() {
-                let ident = &segment.ident.to_string();
-                if ident == expected {
-                    if let PathArguments::AngleBracketed(ref args) = segment.arguments {
-                        return Ok(args.args.to_token_stream());
-                    }
-                } else {
-                    return Ok(quote! { #ty });
+        if let Type::Path(TypePath { path, .. }) = ty
+            && let Some(segment) = path.segments.last()
+        {
+            let ident = &segment.ident.to_string();
+            if ident == expected {
+                if let PathArguments::AngleBracketed(ref args) = segment.arguments {
+                    return Ok(args.args.to_token_stream());
                 }
+            } else {
+                return Ok(quote! { #ty });
             }
         }
 
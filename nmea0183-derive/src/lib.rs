@@ -19,7 +19,7 @@ mod generate;
 mod meta;
 mod parser;
 
-#[doc = include_str!("../README.md")]
+#[cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 #[proc_macro_derive(NmeaParse, attributes(nmea))]
 pub fn derive_nmea_parse(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -11,39 +11,63 @@ use syn::{
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MetaAttributeType {
+    Bits,
+    CollectErrors,
     Cond,
+    Count,
+    Debug,
+    Encode,
     Exact,
+    FromSelector,
+    IfRemainingFields,
     Ignore,
+    InnerChecksum,
     Into,
     Map,
     ParseAs,
     Parser,
+    Peek,
     PreExec,
     PostExec,
     Selector,
+    SelectorRef,
     SelectionError,
     Separator,
     SkipAfter,
     SkipBefore,
+    Verify,
+    Width,
 }
 
 impl MetaAttributeType {
     pub fn from_ident(ident: &Ident) -> Option<Self> {
         match ident.to_string().as_str() {
+            "bits" => Some(Self::Bits),
+            "collect_errors" => Some(Self::CollectErrors),
             "cond" => Some(Self::Cond),
+            "count" => Some(Self::Count),
+            "debug" => Some(Self::Debug),
+            "encode" => Some(Self::Encode),
             "exact" => Some(Self::Exact),
+            "from_selector" => Some(Self::FromSelector),
+            "if_remaining_fields" => Some(Self::IfRemainingFields),
             "ignore" => Some(Self::Ignore),
+            "inner_checksum" => Some(Self::InnerChecksum),
             "into" => Some(Self::Into),
             "map" => Some(Self::Map),
             "parse_as" => Some(Self::ParseAs),
             "parser" => Some(Self::Parser),
+            "peek" => Some(Self::Peek),
             "pre_exec" => Some(Self::PreExec),
             "post_exec" => Some(Self::PostExec),
             "selector" => Some(Self::Selector),
+            "selector_ref" => Some(Self::SelectorRef),
             "selection_error" => Some(Self::SelectionError),
             "separator" => Some(Self::Separator),
             "skip_after" => Some(Self::SkipAfter),
             "skip_before" => Some(Self::SkipBefore),
+            "verify" => Some(Self::Verify),
+            "width" => Some(Self::Width),
             _ => None,
         }
     }
@@ -51,10 +75,15 @@ impl MetaAttributeType {
     fn takes_argument(&self) -> bool {
         matches!(
             self,
-            Self::Cond
+            Self::Bits
+                | Self::Cond
+                | Self::Count
+                | Self::IfRemainingFields
+                | Self::InnerChecksum
                 | Self::Map
                 | Self::ParseAs
                 | Self::Parser
+                | Self::Peek
                 | Self::PreExec
                 | Self::PostExec
                 | Self::Selector
@@ -62,13 +91,15 @@ impl MetaAttributeType {
                 | Self::Separator
                 | Self::SkipAfter
                 | Self::SkipBefore
+                | Self::Verify
+                | Self::Width
         )
     }
 
     fn allowed_multiple(&self) -> bool {
         matches!(
             self,
-            Self::Cond | Self::Map | Self::PreExec | Self::PostExec
+            Self::Cond | Self::Map | Self::PreExec | Self::PostExec | Self::Verify
         )
     }
 }
@@ -76,20 +107,32 @@ impl MetaAttributeType {
 impl Display for MetaAttributeType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
+            Self::Bits => "bits",
+            Self::CollectErrors => "collect_errors",
             Self::Cond => "cond",
+            Self::Count => "count",
+            Self::Debug => "debug",
+            Self::Encode => "encode",
             Self::Exact => "exact",
+            Self::FromSelector => "from_selector",
+            Self::IfRemainingFields => "if_remaining_fields",
             Self::Ignore => "ignore",
+            Self::InnerChecksum => "inner_checksum",
             Self::Into => "into",
             Self::Map => "map",
             Self::ParseAs => "parse_as",
             Self::Parser => "parser",
+            Self::Peek => "peek",
             Self::PreExec => "pre_exec",
             Self::PostExec => "post_exec",
             Self::Selector => "selector",
+            Self::SelectorRef => "selector_ref",
             Self::SelectionError => "selection_error",
             Self::Separator => "separator",
             Self::SkipAfter => "skip_after",
             Self::SkipBefore => "skip_before",
+            Self::Verify => "verify",
+            Self::Width => "width",
         };
         write!(f, "{name}")
     }
@@ -110,10 +153,15 @@ impl MetaAttribute {
     pub fn is_top_level(&self) -> bool {
         matches!(
             self.r#type,
-            MetaAttributeType::Exact
+            MetaAttributeType::CollectErrors
+                | MetaAttributeType::Debug
+                | MetaAttributeType::Encode
+                | MetaAttributeType::Exact
+                | MetaAttributeType::InnerChecksum
                 | MetaAttributeType::PreExec
                 | MetaAttributeType::PostExec
                 | MetaAttributeType::Selector
+                | MetaAttributeType::SelectorRef
                 | MetaAttributeType::SelectionError
                 | MetaAttributeType::Separator
                 | MetaAttributeType::SkipAfter
@@ -124,8 +172,13 @@ impl MetaAttribute {
     pub fn is_field_level(&self) -> bool {
         !matches!(
             self.r#type,
-            MetaAttributeType::Exact
+            MetaAttributeType::CollectErrors
+                | MetaAttributeType::Debug
+                | MetaAttributeType::Encode
+                | MetaAttributeType::Exact
+                | MetaAttributeType::InnerChecksum
                 | MetaAttributeType::Separator
+                | MetaAttributeType::SelectorRef
                 | MetaAttributeType::SelectionError
         )
     }
@@ -292,6 +345,21 @@ pub fn parse_top_level_attributes(attrs: &[Attribute]) -> Result<Vec<MetaAttribu
 }
 
 pub fn parse_field_level_attributes(attrs: &[Attribute]) -> Result<Vec<MetaAttribute>> {
+    parse_nested_attributes(attrs, "field", MetaAttribute::is_field_level)
+}
+
+/// Variants accept the field-level attributes, and may also replace the enum separator.
+pub fn parse_variant_level_attributes(attrs: &[Attribute]) -> Result<Vec<MetaAttribute>> {
+    parse_nested_attributes(attrs, "variant", |meta_attr| {
+        meta_attr.is_field_level() || meta_attr.r#type == MetaAttributeType::Separator
+    })
+}
+
+fn parse_nested_attributes(
+    attrs: &[Attribute],
+    level: &str,
+    is_allowed: fn(&MetaAttribute) -> bool,
+) -> Result<Vec<MetaAttribute>> {
     let mut attributes_set = std::collections::HashSet::new();
 
     attrs
@@ -307,11 +375,11 @@ pub fn parse_field_level_attributes(attrs: &[Attribute]) -> Result<Vec<MetaAttri
         .into_iter()
         .flat_map(|list| list.0.into_iter())
         .map(|meta_attr| {
-            if !meta_attr.is_field_level() {
+            if !is_allowed(&meta_attr) {
                 return Err(Error::new(
                     meta_attr.span(),
                     format!(
-                        "nmea0183-derive: Attribute `{}` is not allowed at the field level",
+                        "nmea0183-derive: Attribute `{}` is not allowed at the {level} level",
                         meta_attr.r#type
                     ),
                 ));
@@ -329,19 +397,25 @@ pub fn parse_field_level_attributes(attrs: &[Attribute]) -> Result<Vec<MetaAttri
                 ));
             }
 
-            // Only one of `parse_as` or `parser` can be used.
-            if meta_attr.r#type == MetaAttributeType::ParseAs && attributes_set.contains("parser") {
-                return Err(Error::new(
-                    meta_attr.span(),
-                    "nmea0183-derive: Attribute `parse_as` cannot be used with `parser` attribute.",
-                ));
-            }
-            if meta_attr.r#type == MetaAttributeType::Parser && attributes_set.contains("parse_as")
-            {
-                return Err(Error::new(
-                    meta_attr.span(),
-                    "nmea0183-derive: Attribute `parser` cannot be used with `parse_as` attribute.",
-                ));
+            // Only one of `from_selector`, `parse_as`, `parser` or `peek` can be used.
+            const EXCLUSIVE: [MetaAttributeType; 4] = [
+                MetaAttributeType::FromSelector,
+                MetaAttributeType::ParseAs,
+                MetaAttributeType::Parser,
+                MetaAttributeType::Peek,
+            ];
+            if EXCLUSIVE.contains(&meta_attr.r#type) {
+                for other in EXCLUSIVE {
+                    if other != meta_attr.r#type && attributes_set.contains(&other.to_string()) {
+                        return Err(Error::new(
+                            meta_attr.span(),
+                            format!(
+                                "nmea0183-derive: Attribute `{}` cannot be used with `{other}` attribute.",
+                                meta_attr.r#type
+                            ),
+                        ));
+                    }
+                }
             }
 
             Ok(meta_attr)
@@ -8,6 +8,10 @@ pub enum Parser {
         parser: Box<Parser>,
         condition: TokenStream,
     },
+    Count {
+        parser: Box<Parser>,
+        count: TokenStream,
+    },
     Into(Box<Parser>),
     Map {
         parser: Box<Parser>,
@@ -18,10 +22,27 @@ pub enum Parser {
         ty: Box<Type>,
         separator: Option<TokenStream>,
     },
+    Verify {
+        parser: Box<Parser>,
+        verify: TokenStream,
+    },
 }
 
 impl Parser {
-    pub fn as_nmeaparse(self, error_type: &syn::Ident, nmea_lifetime: &syn::Lifetime) -> Self {
+    /// Returns the type parsed with its own `NmeaParse` implementation, if any
+    pub fn parsed_type(&self) -> Option<&Type> {
+        match self {
+            Self::Cond { parser, .. }
+            | Self::Count { parser, .. }
+            | Self::Into(parser)
+            | Self::Map { parser, .. }
+            | Self::Verify { parser, .. } => parser.parsed_type(),
+            Self::Raw(_) => None,
+            Self::Type { ty, .. } => Some(ty),
+        }
+    }
+
+    pub fn into_nmeaparse(self, error_type: &syn::Ident, nmea_lifetime: &syn::Lifetime) -> Self {
         match self {
             Self::Type { ty, separator } => {
                 let parser = if let Some(separator) = separator {
@@ -42,6 +63,9 @@ impl ToTokens for Parser {
             Self::Cond { parser, condition } => {
                 quote! { nom::combinator::cond(#condition, #parser) }
             }
+            Self::Count { parser, count } => {
+                quote! { nom::multi::count(#parser, (#count) as usize) }
+            }
             Self::Into(parser) => {
                 quote! { nom::combinator::into(#parser) }
             }
@@ -56,6 +80,9 @@ impl ToTokens for Parser {
                     quote! { <#ty>::parse }
                 }
             }
+            Self::Verify { parser, verify } => {
+                quote! { nom::combinator::verify(#parser, #verify) }
+            }
         };
 
         tokens.extend(token_stream);
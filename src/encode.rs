@@ -0,0 +1,156 @@
+use alloc::{string::String, vec::Vec};
+
+/// Trait for encoding types back into NMEA 0183 sentence fields.
+///
+/// The `NmeaEncode` trait is the inverse of [`NmeaParse`](crate::NmeaParse): it writes the
+/// content of a value in the format expected by its parser, so that parsed sentences can be
+/// modified and re-emitted. Only the content is produced, without the `$` prefix, the checksum
+/// or the line ending.
+///
+/// Implementations are provided for primitive types, `Option<T>`, arrays and `Vec<T>`, and the
+/// trait can be derived with the `#[nmea(encode)]` attribute of the
+/// [`NmeaParse`](crate::NmeaParse) derive macro.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::NmeaEncode;
+///
+/// assert_eq!(42u8.encode(), "42");
+/// assert_eq!(Some(2.5f32).encode(), "2.5");
+/// assert_eq!(None::<u8>.encode(), "");
+/// assert_eq!(vec![Some(1), None, Some(3)].encode(), "1,,3");
+/// ```
+pub trait NmeaEncode {
+    /// Appends the encoded value to the given string.
+    fn encode_to(&self, out: &mut String);
+
+    /// Returns the encoded value as a new string.
+    fn encode(&self) -> String {
+        let mut out = String::new();
+        self.encode_to(&mut out);
+        out
+    }
+}
+
+macro_rules! impl_display_type {
+    ($($t:ty),*) => ($(
+        impl NmeaEncode for $t {
+            fn encode_to(&self, out: &mut String) {
+                use core::fmt::Write;
+                let _ = write!(out, "{self}");
+            }
+        }
+    )*)
+}
+
+impl_display_type!(u8, u16, u32, u64, u128, usize);
+impl_display_type!(i8, i16, i32, i64, i128, isize);
+impl_display_type!(f32, f64);
+
+impl NmeaEncode for char {
+    fn encode_to(&self, out: &mut String) {
+        out.push(*self);
+    }
+}
+
+impl NmeaEncode for bool {
+    fn encode_to(&self, out: &mut String) {
+        out.push(if *self { '1' } else { '0' });
+    }
+}
+
+impl NmeaEncode for str {
+    fn encode_to(&self, out: &mut String) {
+        out.push_str(self);
+    }
+}
+
+impl<T> NmeaEncode for &T
+where
+    T: NmeaEncode + ?Sized,
+{
+    fn encode_to(&self, out: &mut String) {
+        (*self).encode_to(out);
+    }
+}
+
+impl<T> NmeaEncode for Option<T>
+where
+    T: NmeaEncode,
+{
+    fn encode_to(&self, out: &mut String) {
+        if let Some(value) = self {
+            value.encode_to(out);
+        }
+    }
+}
+
+impl<T> NmeaEncode for [T]
+where
+    T: NmeaEncode,
+{
+    fn encode_to(&self, out: &mut String) {
+        for (index, elem) in self.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            elem.encode_to(out);
+        }
+    }
+}
+
+impl<T, const N: usize> NmeaEncode for [T; N]
+where
+    T: NmeaEncode,
+{
+    fn encode_to(&self, out: &mut String) {
+        self.as_slice().encode_to(out);
+    }
+}
+
+impl<T> NmeaEncode for Vec<T>
+where
+    T: NmeaEncode,
+{
+    fn encode_to(&self, out: &mut String) {
+        self.as_slice().encode_to(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::{IResult, NmeaParse};
+
+    #[test]
+    fn test_encode_primitives() {
+        assert_eq!(42u8.encode(), "42");
+        assert_eq!((-7i16).encode(), "-7");
+        assert_eq!(12.75f64.encode(), "12.75");
+        assert_eq!('A'.encode(), "A");
+        assert_eq!(true.encode(), "1");
+        assert_eq!(false.encode(), "0");
+        assert_eq!("GGA".encode(), "GGA");
+    }
+
+    #[test]
+    fn test_encode_containers() {
+        assert_eq!(Some(5u8).encode(), "5");
+        assert_eq!(None::<u8>.encode(), "");
+        assert_eq!([1u8, 2, 3].encode(), "1,2,3");
+        assert_eq!(vec![Some(1u8), None, Some(3)].encode(), "1,,3");
+        assert_eq!(Vec::<u8>::new().encode(), "");
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let values = vec![Some(1.5f32), None, Some(-3.0)];
+        let encoded = values.encode();
+
+        let result: IResult<_, _> = <Vec<Option<f32>>>::parse(encoded.as_str());
+        assert_eq!(result, Ok(("", values)));
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! This module defines the error types used throughout the NMEA parsing library.
 
-use nom::error::{ErrorKind, FromExternalError, ParseError};
-use std::fmt::Debug;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Debug, Display};
+use nom::{
+    Offset,
+    error::{ContextError, ErrorKind, FromExternalError, ParseError},
+};
 
 /// Holds the result of parsing functions.
 ///
@@ -15,6 +20,9 @@ use std::fmt::Debug;
 ///
 /// Outside of the parsing code, you can use the [nom::Finish::finish] method to convert
 /// it to a more common result type.
+///
+/// The default inner error `nom::error::Error<I>` only keeps the innermost error of a
+/// failed parse. Use [`ErrorTrace`] as the inner error to keep the whole chain.
 pub type IResult<I, O, E = nom::error::Error<I>> = nom::IResult<I, O, Error<I, E>>;
 
 /// Represents all possible errors that can occur during NMEA message parsing.
@@ -28,6 +36,13 @@ pub enum Error<I, E> {
     /// NMEA messages must be ASCII-only for proper parsing and checksum calculation.
     NonAscii,
 
+    /// The checksum of the sentence was required but missing.
+    ///
+    /// Returned when the checksum mode is
+    /// [`ChecksumMode::Required`](crate::ChecksumMode::Required) and the sentence
+    /// has no `*CC` checksum field.
+    ChecksumMissing,
+
     /// The checksum of the sentence was corrupt or incorrect.
     ///
     /// Contains both the expected checksum (calculated from the message content)
@@ -39,6 +54,17 @@ pub enum Error<I, E> {
         found: u8,
     },
 
+    /// The sentence was longer than the configured maximum length.
+    ///
+    /// Returned when a [`max_length`](crate::Nmea0183ParserBuilder::max_length) is set
+    /// and the sentence exceeds it.
+    TooLong {
+        /// The configured maximum length
+        max_length: usize,
+        /// The length of the sentence
+        length: usize,
+    },
+
     /// The sentence could not be parsed because its format was invalid.
     ///
     /// This wraps nom's standard parsing errors and provides context about
@@ -66,6 +92,74 @@ pub enum Error<I, E> {
     Unknown,
 }
 
+/// An error carrying the raw sentence that caused it.
+///
+/// Returned by the parsers built with
+/// [`Nmea0183ParserBuilder::build_logged`](crate::Nmea0183ParserBuilder::build_logged),
+/// so that a single catch-all logger can record the offending sentence along with the error,
+/// whether the failure happened in the framing or deep in the content parser.
+#[derive(Debug, PartialEq)]
+pub struct LoggedError<I, E> {
+    /// The raw sentence given to the parser
+    pub raw: I,
+    /// The error returned by the parser
+    pub error: nom::Err<Error<I, E>>,
+}
+
+#[cfg(feature = "alloc")]
+impl Error<&str, nom::error::Error<&str>> {
+    /// Converts the error into an owned error, copying the input it borrows.
+    ///
+    /// This allows returning the error past the lifetime of the input, e.g. to send it
+    /// to another thread.
+    pub fn into_owned(self) -> Error<String, nom::error::Error<String>> {
+        match self {
+            Error::NonAscii => Error::NonAscii,
+            Error::ChecksumMissing => Error::ChecksumMissing,
+            Error::ChecksumMismatch { expected, found } => {
+                Error::ChecksumMismatch { expected, found }
+            }
+            Error::TooLong { max_length, length } => Error::TooLong { max_length, length },
+            Error::ParsingError(error) => Error::ParsingError(error.cloned()),
+            Error::UnrecognizedMessage(input) => Error::UnrecognizedMessage(input.into()),
+            Error::InvalidField(input) => Error::InvalidField(input.into()),
+            Error::Unknown => Error::Unknown,
+        }
+    }
+}
+
+impl<I, E> Display for Error<I, E>
+where
+    I: Debug,
+    E: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NonAscii => write!(f, "input contains non-ASCII characters"),
+            Error::ChecksumMissing => write!(f, "checksum missing"),
+            Error::ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch: expected {expected:02X}, found {found:02X}"
+            ),
+            Error::TooLong { max_length, length } => write!(
+                f,
+                "sentence too long: {length} characters, maximum is {max_length}"
+            ),
+            Error::ParsingError(e) => write!(f, "parsing error: {e:?}"),
+            Error::UnrecognizedMessage(i) => write!(f, "unrecognized message: {i:?}"),
+            Error::InvalidField(i) => write!(f, "invalid field: {i:?}"),
+            Error::Unknown => write!(f, "unknown error"),
+        }
+    }
+}
+
+impl<I, E> core::error::Error for Error<I, E>
+where
+    I: Debug,
+    E: Debug,
+{
+}
+
 impl<I, E> ParseError<I> for Error<I, E>
 where
     E: ParseError<I>,
@@ -74,8 +168,36 @@ where
         Error::ParsingError(E::from_error_kind(input, kind))
     }
 
-    fn append(_: I, _: ErrorKind, other: Self) -> Self {
-        other
+    fn append(input: I, kind: ErrorKind, other: Self) -> Self {
+        match other {
+            Error::ParsingError(e) => Error::ParsingError(E::append(input, kind, e)),
+            other => other,
+        }
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Error::ParsingError(E::from_char(input, c))
+    }
+
+    fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Error::ParsingError(e), Error::ParsingError(other)) => {
+                Error::ParsingError(e.or(other))
+            }
+            (_, other) => other,
+        }
+    }
+}
+
+impl<I, E> ContextError<I> for Error<I, E>
+where
+    E: ContextError<I>,
+{
+    fn add_context(input: I, ctx: &'static str, other: Self) -> Self {
+        match other {
+            Error::ParsingError(e) => Error::ParsingError(E::add_context(input, ctx, e)),
+            other => other,
+        }
     }
 }
 
@@ -87,3 +209,266 @@ where
         Error::ParsingError(E::from_external_error(input, kind, e))
     }
 }
+
+/// An inner error recording the whole chain of errors of a failed parse.
+///
+/// The default inner error of [`IResult`], `nom::error::Error<I>`, only keeps the innermost
+/// error: the errors appended by nom's combinators while unwinding, e.g. [`ErrorKind::Alt`],
+/// and the contexts added with [`nom::error::context`] are discarded. This is cheap and does
+/// not allocate, which suits high-rate streams where a failed sentence is simply dropped.
+///
+/// `ErrorTrace` keeps all of them, from the innermost to the outermost, at the cost of an
+/// allocation on each failure, which suits diagnostics and strict validation pipelines.
+/// Since [`Error`] forwards them to its inner error, the chain is preserved through the
+/// framing parser in [`Error::ParsingError`].
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{Error, ErrorTrace, ErrorTraceKind, IResult, Nmea0183ParserBuilder};
+/// use nom::{
+///     Parser,
+///     character::complete::{char, digit1},
+///     error::{ErrorKind, context},
+///     sequence::preceded,
+/// };
+///
+/// fn content_parser(i: &str) -> IResult<&str, &str, ErrorTrace<&str>> {
+///     context("sentence", preceded(char('A'), preceded(char(','), context("value", digit1))))
+///         .parse(i)
+/// }
+///
+/// let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+///
+/// let Err(nom::Err::Error(Error::ParsingError(trace))) = parser("$A,x*15\r\n") else {
+///     unreachable!()
+/// };
+/// assert_eq!(
+///     trace.errors,
+///     [
+///         ("x", ErrorTraceKind::Nom(ErrorKind::Digit)),
+///         ("x", ErrorTraceKind::Context("value")),
+///         ("A,x", ErrorTraceKind::Context("sentence")),
+///     ]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorTrace<I> {
+    /// The errors of the chain, from the innermost to the outermost
+    pub errors: Vec<(I, ErrorTraceKind)>,
+}
+
+/// A single error of an [`ErrorTrace`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorTraceKind {
+    /// An error returned by a nom parser or appended by a nom combinator
+    Nom(ErrorKind),
+    /// The character expected by a `char` parser
+    Char(char),
+    /// A context added with [`nom::error::context`]
+    Context(&'static str),
+}
+
+#[cfg(feature = "alloc")]
+impl<I> ParseError<I> for ErrorTrace<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        ErrorTrace {
+            errors: vec![(input, ErrorTraceKind::Nom(kind))],
+        }
+    }
+
+    fn append(input: I, kind: ErrorKind, mut other: Self) -> Self {
+        other.errors.push((input, ErrorTraceKind::Nom(kind)));
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        ErrorTrace {
+            errors: vec![(input, ErrorTraceKind::Char(c))],
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> ContextError<I> for ErrorTrace<I> {
+    fn add_context(input: I, ctx: &'static str, mut other: Self) -> Self {
+        other.errors.push((input, ErrorTraceKind::Context(ctx)));
+        other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, EX> FromExternalError<I, EX> for ErrorTrace<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: EX) -> Self {
+        ErrorTrace::from_error_kind(input, kind)
+    }
+}
+
+/// Returns the byte offset in `original` at which parsing failed.
+///
+/// The offset is computed between the original input and the remaining input recorded
+/// in the error, so `original` must be the input the failing parser was called with, or
+/// any input it is a part of, e.g. the full sentence given to the framing parser. This
+/// makes it possible to point at the exact column of a malformed field.
+///
+/// Returns [`None`] for errors that do not record a position, such as
+/// [`Error::NonAscii`] or [`Error::ChecksumMismatch`].
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, Nmea0183ParserBuilder, error_offset};
+/// use nom::{Parser, bytes::complete::tag, character::complete::digit1, sequence::preceded};
+///
+/// fn content_parser(i: &str) -> IResult<&str, &str> {
+///     preceded(tag("GPGGA,"), digit1).parse(i)
+/// }
+///
+/// let sentence = "$GPGGA,data*6A\r\n";
+/// let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+///
+/// let Err(nom::Err::Error(error)) = parser(sentence) else {
+///     unreachable!()
+/// };
+/// assert_eq!(error_offset(sentence, &error), Some(7));
+/// ```
+pub fn error_offset<I>(original: I, err: &Error<I, nom::error::Error<I>>) -> Option<usize>
+where
+    I: Offset,
+{
+    match err {
+        Error::ParsingError(error) => Some(original.offset(&error.input)),
+        Error::UnrecognizedMessage(input) | Error::InvalidField(input) => {
+            Some(original.offset(input))
+        }
+        Error::NonAscii
+        | Error::ChecksumMissing
+        | Error::ChecksumMismatch { .. }
+        | Error::TooLong { .. }
+        | Error::Unknown => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        let cases: [(Error<&str, nom::error::Error<&str>>, &str); 8] = [
+            (Error::NonAscii, "input contains non-ASCII characters"),
+            (Error::ChecksumMissing, "checksum missing"),
+            (
+                Error::ChecksumMismatch {
+                    expected: 0x73,
+                    found: 0x99,
+                },
+                "checksum mismatch: expected 73, found 99",
+            ),
+            (
+                Error::TooLong {
+                    max_length: 82,
+                    length: 90,
+                },
+                "sentence too long: 90 characters, maximum is 82",
+            ),
+            (
+                Error::from_error_kind("abc", ErrorKind::Digit),
+                r#"parsing error: Error { input: "abc", code: Digit }"#,
+            ),
+            (
+                Error::UnrecognizedMessage("GPXYZ"),
+                r#"unrecognized message: "GPXYZ""#,
+            ),
+            (Error::InvalidField("13"), r#"invalid field: "13""#),
+            (Error::Unknown, "unknown error"),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+
+        let error: Box<dyn core::error::Error> =
+            Box::new(Error::<&str, nom::error::Error<&str>>::ChecksumMissing);
+        assert_eq!(error.to_string(), "checksum missing");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_error_trace() {
+        use nom::{
+            Parser,
+            branch::alt,
+            character::complete::{char, digit1},
+            error::context,
+            sequence::preceded,
+        };
+
+        fn content_parser<'a, E>(i: &'a str) -> nom::IResult<&'a str, &'a str, E>
+        where
+            E: ParseError<&'a str> + ContextError<&'a str>,
+        {
+            preceded(
+                context("header", alt((char('A'), char('B')))),
+                preceded(char(','), digit1),
+            )
+            .parse(i)
+        }
+
+        // The default inner error only keeps the innermost error
+        let result: IResult<_, _> = content_parser("C,1");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::from_error_kind(
+                "C,1",
+                ErrorKind::Char
+            )))
+        );
+
+        // The trace keeps the whole chain
+        let result: IResult<_, _, ErrorTrace<_>> = content_parser("C,1");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(ErrorTrace {
+                errors: vec![
+                    ("C,1", ErrorTraceKind::Char('B')),
+                    ("C,1", ErrorTraceKind::Nom(ErrorKind::Alt)),
+                    ("C,1", ErrorTraceKind::Context("header")),
+                ]
+            })))
+        );
+    }
+
+    #[test]
+    fn test_error_offset() {
+        let original = "GPGGA,123,abc";
+
+        let error = Error::from_error_kind(&original[10..], ErrorKind::Digit);
+        assert_eq!(error_offset(original, &error), Some(10));
+
+        let error = Error::InvalidField(&original[6..9]);
+        assert_eq!(error_offset(original, &error), Some(6));
+
+        let error = Error::UnrecognizedMessage(original);
+        assert_eq!(error_offset(original, &error), Some(0));
+
+        let error = Error::ChecksumMismatch {
+            expected: 0x4A,
+            found: 0x00,
+        };
+        assert_eq!(error_offset(original, &error), None);
+        assert_eq!(error_offset(original, &Error::NonAscii), None);
+    }
+
+    #[test]
+    fn test_error_offset_bytes() {
+        let original: &[u8] = b"GPGGA,123,abc";
+
+        let error = Error::from_error_kind(&original[13..], ErrorKind::Eof);
+        assert_eq!(error_offset(original, &error), Some(13));
+    }
+}
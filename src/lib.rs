@@ -44,8 +44,8 @@
 //! ## 📦 Key Features
 //!
 //! - ✅ ASCII-only validation
-//! - ✅ Required or optional checksum validation
-//! - ✅ Required or forbidden CRLF ending enforcement
+//! - ✅ Required, optional or ignored checksum validation
+//! - ✅ Required, optional or forbidden CRLF ending enforcement
 //! - ✅ Zero-allocation parsing
 //! - ✅ Built on `nom` combinators
 //! - ✅ Fully pluggable content parser (you bring the domain logic)
@@ -288,6 +288,7 @@
 //! For example, you can define a struct and derive the `NmeaParse` trait like this:
 //!
 //! ```rust
+//! # #[cfg(feature = "derive")] {
 //! use nmea0183_parser::NmeaParse;
 //!
 //! #[derive(NmeaParse)]
@@ -296,6 +297,7 @@
 //!     pub value: f64,
 //!     pub timestamp: u64,
 //! }
+//! # }
 //! ```
 //!
 //! This will generate an implementation of the `NmeaParse` trait for the `Data` struct,
@@ -317,6 +319,7 @@
 //! You can now parse an input containing NMEA 0183-style content into a `Data` struct:
 //!
 //! ```rust
+//! # #[cfg(feature = "derive")] {
 //! # use nmea0183_parser::{IResult, NmeaParse};
 //! # use nom::error::ParseError;
 //! #
@@ -333,12 +336,14 @@
 //! assert_eq!(data.id, 123);
 //! assert_eq!(data.value, 45.67);
 //! assert_eq!(data.timestamp, 1622547800);
+//! # }
 //! ```
 //!
 //! The macro also supports enums, which require a `selector` attribute to determine which
 //! variant to parse:
 //!
 //! ```rust
+//! # #[cfg(feature = "derive")] {
 //! use nmea0183_parser::{Error, IResult, NmeaParse};
 //!
 //! # #[derive(Debug)]
@@ -368,6 +373,7 @@
 //!         ..
 //!     }))
 //! ));
+//! # }
 //! ```
 //!
 //! You can use the `#[derive(NmeaParse)]` attribute on your structs and enums to automatically
@@ -394,6 +400,7 @@
 //! content parser:
 //!
 //! ```rust
+//! # #[cfg(feature = "nmea-content")] {
 //! use nmea0183_parser::{
 //!     IResult, Nmea0183ParserBuilder, NmeaParse,
 //!     nmea_content::{GGA, Location, NmeaSentence, Quality},
@@ -426,6 +433,7 @@
 //!         ..
 //!     })
 //! ));
+//! # }
 //! ```
 //!
 //! > **Note:** While the `Nmea0183ParserBuilder` framing parser can accept both `&str` and `&[u8]`
@@ -434,6 +442,9 @@
 //!
 //! ### Supported NMEA Sentences
 //!
+//! - [`ALM`](https://gpsd.gitlab.io/gpsd/NMEA.html#_alm_gps_almanac_data) - GPS Almanac Data
+//! - [`BWC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_bwc_bearing_distance_to_waypoint_great_circle) - Bearing & Distance to Waypoint: Great Circle
+//! - [`BWR`](https://gpsd.gitlab.io/gpsd/NMEA.html#_bwr_bearing_and_distance_to_waypoint_rhumb_line) - Bearing and Distance to Waypoint: Rhumb Line
 //! - [`DBT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dbt_depth_below_transducer) - Depth Below Transducer
 //! - [`DPT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dpt_depth_of_water) - Depth of Water
 //! - [`GGA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gga_global_positioning_system_fix_data) - Global Positioning System Fix Data
@@ -441,9 +452,23 @@
 //! - [`GSA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gsa_gps_dop_and_active_satellites) - GPS DOP and Active Satellites
 //! - [`GSV`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gsv_satellites_in_view) - Satellites in View
 //! - [`RMC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rmc_recommended_minimum_navigation_information) - Recommended Minimum Navigation Information
+//! - [`RTE`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rte_routes) - Routes
+//! - [`VBW`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vbw_dual_groundwater_speed) - Dual Ground/Water Speed
 //! - [`VTG`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vtg_track_made_good_and_ground_speed) - Track made good and Ground speed
+//! - [`WPL`](https://gpsd.gitlab.io/gpsd/NMEA.html#_wpl_waypoint_location) - Waypoint Location
 //! - [`ZDA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_zda_time_date_utc_day_month_year_and_local_time_zone) - Time & Date: UTC, day, month, year and local time zone
 //!
+//! Proprietary sentences, which start with `P` and a three-character manufacturer code instead
+//! of a talker ID (e.g. `$PGRME` or `$PUBX`), are parsed into the `NmeaSentence::Proprietary`
+//! variant, keeping the manufacturer code and the raw content for vendor extensions.
+//!
+//! ### Talker IDs
+//!
+//! `NmeaSentence::parse` skips the two-character talker ID preceding the sentence type.
+//! Use `NmeaSentence::parse_with_talker` instead to also get it as a `TalkerId`, e.g. to tell
+//! apart the `GSV` sentences of each constellation on multi-GNSS receivers. Unknown talker
+//! IDs are kept as `TalkerId::Other`.
+//!
 //! ### NMEA Version Support
 //!
 //! Different NMEA versions may include additional fields in certain sentence types.
@@ -458,19 +483,122 @@
 //!
 //! For specific field differences between versions, please refer to the
 //! [NMEA 0183 standard documentation](https://gpsd.gitlab.io/gpsd/NMEA.html).
+//!
+//! `NmeaSentence::parse` rejects sentences carrying fields of a later version than the enabled
+//! one. `NmeaSentence::parse_lenient` ignores such trailing fields and returns them as the
+//! remaining input instead, at the cost of also accepting other unexpected trailing content.
+//!
+//! ### Capacity Overflow
+//!
+//! Sentences with a bounded number of repeated fields, such as the PRN numbers of `GSA` and
+//! the satellites of `GSV`, are parsed into fixed-capacity `heapless::Vec`s. By default,
+//! elements beyond the capacity are silently dropped or left unparsed. Enabling the
+//! `strict-capacity` feature rejects such over-long sentences instead, so that embedded
+//! users get deterministic errors rather than truncated data.
+//!
+//! Text fields, such as the waypoint IDs of `WPL` and `RTE`, are parsed into `heapless::String`s,
+//! which always reject a field longer than their capacity. Custom sentences that keep the
+//! input around can borrow their text fields as `&str` instead.
+//!
+//! ### `no_std` Support
+//!
+//! The crate is `no_std` compatible when its default `std` feature is disabled. The framing
+//! parser and the content parsers do not allocate, so they work without an allocator, and
+//! `format_checksum_into` formats checksums into a stack buffer. The `alloc` feature
+//! re-enables the allocating APIs: `Nmea0183Encoder`, `NmeaEncode`, `normalize` and the `NmeaParse`
+//! implementation for `Vec<T>`. `SatelliteTracker` and the `utm` and `geo` features require `std`.
+//!
+//! ```toml
+//! [dependencies]
+//! nmea0183-parser = { version = "0.3", default-features = false, features = ["nmea-v4-11"] }
+//! ```
+//!
+//! ### Projected Coordinates
+//!
+//! Enabling the `utm` feature adds `Location::to_utm` and `Location::to_mgrs`, converting
+//! parsed positions to UTM coordinates and MGRS grid references on the WGS-84 ellipsoid,
+//! ready to be used in GIS pipelines.
+//!
+//! Enabling the `geo` feature adds `Location::distance_to` and `Location::bearing_to`, returning
+//! the great-circle distance in meters and the initial bearing in degrees true between two fixes.
+//!
+//! ### chrono Types
+//!
+//! Enabling the `chrono` feature implements `NmeaParse` for `chrono::NaiveTime`, `chrono::NaiveDate`
+//! and `chrono::FixedOffset`, parsing the same `hhmmss.ss`, `ddmmyy` and `hh,mm` fields as the
+//! `time` implementations, so that custom sentences can use `chrono` types. The built-in sentences
+//! keep using the `time` types.
+//!
+//! ### Typed Quantities
+//!
+//! Enabling the `uom` feature adds accessors returning `uom` quantities for the depth, altitude
+//! and speed fields, e.g. `DBT::water_depth_length` and `VTG::speed_over_ground_velocity`, so that
+//! call sites convert units explicitly instead of assuming meters or knots.
+//!
+//! ### u-blox Sentences
+//!
+//! Enabling the `ublox` feature adds the `PUBX00` struct for the u-blox `$PUBX,00` position
+//! sentence, parsed from the `NmeaSentence::Proprietary` variant with `Proprietary::pubx00`.
+//!
+//! ### Garmin Sentences
+//!
+//! Enabling the `garmin` feature adds the `PGRME` struct for the Garmin `$PGRME` estimated
+//! position error sentence, parsed from the `NmeaSentence::Proprietary` variant with
+//! `Proprietary::pgrme`.
+//!
+//! ### Custom Sentences
+//!
+//! The field parsers used by the built-in sentences, such as `location`, `with_unit` and
+//! `date_full_year`, are exposed in the `nmea_content::parsers` module, so that custom sentences
+//! can reuse them with the `parser` attribute of the `NmeaParse` derive macro.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod encode;
 mod error;
 mod nmea0183;
 #[cfg(feature = "nmea-content")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nmea-content")))]
 pub mod nmea_content;
 mod parse;
+mod stream;
 
-pub use error::{Error, IResult};
-pub use nmea0183::{ChecksumMode, LineEndingMode, Nmea0183ParserBuilder};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use encode::NmeaEncode;
+pub use error::{Error, IResult, LoggedError, error_offset};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use error::{ErrorTrace, ErrorTraceKind};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use nmea0183::Nmea0183Encoder;
+pub use nmea0183::{
+    ChecksumMode, ChecksumStatus, ChecksumWidth, LineEndingMode, Nmea0183ParserBuilder,
+    SentenceMeta, StartDelimiter, TagBlock, checksum_bytes, format_checksum_into,
+    validate_checksum,
+};
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use nmea0183_derive::NmeaParse;
 pub use parse::NmeaParse;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use parse::{parse_preceded_until, parse_until};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use stream::normalize;
+pub use stream::{Sentences, sentences, split_sentences};
+
+/// Items used by the code generated by the `NmeaParse` derive macro, not part of the public API.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+    pub use alloc::vec::Vec;
+}
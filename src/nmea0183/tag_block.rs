@@ -0,0 +1,137 @@
+//! # NMEA 4.10 Tag Blocks
+//!
+//! This module provides the parser for the tag blocks that may precede NMEA 0183 sentences,
+//! in the format `\s:station,c:1234567890*CC\`.
+
+use nom::{
+    AsBytes, AsChar, Compare, Err, FindSubstring, Input, Parser,
+    branch::alt,
+    bytes::complete::{take_till, take_until},
+    character::complete::{anychar, char},
+    combinator::{all_consuming, opt, rest},
+    error::ParseError,
+    sequence::delimited,
+};
+
+use crate::{ChecksumMode, ChecksumWidth, Error, IResult, LineEndingMode, checksum_bytes};
+
+use super::checksum_crlf_with_width;
+
+/// An NMEA 4.10 tag block preceding a sentence.
+///
+/// Multiplexed marine networks and AIS feeds may prefix sentences with a tag block, e.g.
+/// `\s:station,c:1234567890*CC\$GPGGA,...`, carrying information about the sentence such
+/// as the station that received it. Tag blocks are only accepted if
+/// [`Nmea0183ParserBuilder::tag_block`](crate::Nmea0183ParserBuilder::tag_block) is set,
+/// and are returned by the parsers built with
+/// [`Nmea0183ParserBuilder::build_with_tag_block`](crate::Nmea0183ParserBuilder::build_with_tag_block).
+///
+/// Parameters other than the ones below, such as the destination (`d:`) or the sentence
+/// grouping (`g:`), are skipped.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagBlock<I> {
+    /// Source identification, e.g. the receiving station (`s:`)
+    pub source: Option<I>,
+    /// UNIX time, in seconds or milliseconds depending on the source (`c:`)
+    pub timestamp: Option<u64>,
+    /// Line count (`n:`)
+    pub line_count: Option<u32>,
+    /// Relative time (`r:`)
+    pub relative_time: Option<u64>,
+    /// Free text (`t:`)
+    pub text: Option<I>,
+}
+
+/// Creates a parser for a tag block, from the opening backslash to the closing one.
+///
+/// The checksum of the tag block is handled according to the checksum mode, like the one
+/// of the sentence. Numeric parameters that are not valid numbers are reported as
+/// [`Error::InvalidField`].
+pub(super) fn tag_block<'a, I, E>(
+    checksum_mode: ChecksumMode,
+    strict_hex: bool,
+) -> impl FnMut(I) -> IResult<I, TagBlock<I>, E>
+where
+    I: Input + AsBytes + Compare<&'a str> + FindSubstring<&'a str>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    move |i: I| {
+        let (i, block) = delimited(
+            char('\\'),
+            take_till(|c: <I as Input>::Item| c.as_char() == '\\'),
+            char('\\'),
+        )
+        .parse(i)?;
+
+        let (cc, parameters) = alt((take_until("*"), rest)).parse(block)?;
+        if checksum_mode == ChecksumMode::Required && !cc.as_bytes().starts_with(b"*") {
+            return Err(Err::Error(Error::ChecksumMissing));
+        }
+
+        let (_, cc) = checksum_crlf_with_width(
+            checksum_mode,
+            LineEndingMode::Forbidden,
+            ChecksumWidth::Two,
+            strict_hex,
+        )
+        .parse(cc)?;
+        let calc_cc = checksum_bytes(parameters.as_bytes());
+        if let Some(cc) = cc
+            && cc != calc_cc
+            && checksum_mode != ChecksumMode::Ignore
+        {
+            return Err(Err::Error(Error::ChecksumMismatch {
+                expected: calc_cc,
+                found: cc,
+            }));
+        }
+
+        let mut tag_block = TagBlock {
+            source: None,
+            timestamp: None,
+            line_count: None,
+            relative_time: None,
+            text: None,
+        };
+
+        let mut parameters = parameters;
+        while parameters.input_len() > 0 {
+            let (remaining, (code, _, value)) = (
+                anychar,
+                char(':'),
+                take_till(|c: <I as Input>::Item| c.as_char() == ','),
+            )
+                .parse(parameters)?;
+
+            match code {
+                's' => tag_block.source = Some(value),
+                'c' => tag_block.timestamp = Some(number(value, nom::character::complete::u64)?),
+                'n' => tag_block.line_count = Some(number(value, nom::character::complete::u32)?),
+                'r' => {
+                    tag_block.relative_time = Some(number(value, nom::character::complete::u64)?)
+                }
+                't' => tag_block.text = Some(value),
+                _ => {}
+            }
+
+            (parameters, _) = opt(char(',')).parse(remaining)?;
+        }
+
+        Ok((i, tag_block))
+    }
+}
+
+/// Parses the whole value of a numeric parameter, reporting it as an invalid field
+/// if it is not a valid number.
+fn number<I, O, E, F>(value: I, parser: F) -> Result<O, Err<Error<I, E>>>
+where
+    I: Input,
+    F: Parser<I, Output = O, Error = Error<I, E>>,
+    E: ParseError<I>,
+{
+    all_consuming(parser)
+        .parse(value.clone())
+        .map(|(_, number)| number)
+        .map_err(|_| Err::Error(Error::InvalidField(value)))
+}
@@ -0,0 +1,35 @@
+use nom::Parser;
+
+use crate::{Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    Ok(("", i))
+}
+
+#[test]
+fn test_assume_ascii_disabled() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    let res = parser.parse("$GPGGA,data*6A\r\n");
+    assert_eq!(res, Ok(("", "GPGGA,data")));
+
+    let res = parser.parse("$GPGGA,datä*6A\r\n");
+    assert_eq!(res, Err(nom::Err::Error(Error::NonAscii)));
+}
+
+#[test]
+fn test_assume_ascii_enabled() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .assume_ascii(true)
+        .build(content_parser);
+
+    let res = parser.parse("$GPGGA,data*6A\r\n");
+    assert_eq!(res, Ok(("", "GPGGA,data")));
+
+    // Non-ASCII input is no longer rejected up front; it fails on the checksum instead.
+    let res = parser.parse("$GPGGA,datä*6A\r\n");
+    assert!(matches!(
+        res,
+        Err(nom::Err::Error(Error::ChecksumMismatch { found: 0x6A, .. }))
+    ));
+}
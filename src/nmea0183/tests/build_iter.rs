@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+
+use nom::{Parser, bytes::complete::tag, character::complete::digit1, sequence::preceded};
+
+use crate::{ChecksumMode, Error, IResult, LineEndingMode, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, u32> {
+    preceded(tag("GPGGA,"), digit1).map_res(str::parse).parse(i)
+}
+
+#[test]
+fn test_build_iter() {
+    let log = "$GPGGA,123*4A\r\n$GPGGA,456*4D\r\n";
+
+    let results: Vec<_> = Nmea0183ParserBuilder::new()
+        .build_iter(content_parser, log)
+        .collect();
+    assert_eq!(results, [Ok(123), Ok(456)]);
+}
+
+#[test]
+fn test_build_iter_blank_lines() {
+    let log = "\r\n$GPGGA,123*4A\r\n\r\n  \r\n\n$GPGGA,456*4D\r\n\r\n";
+
+    let results: Vec<_> = Nmea0183ParserBuilder::new()
+        .line_ending_mode(LineEndingMode::CrlfOrLf)
+        .build_iter(content_parser, log)
+        .collect();
+    assert_eq!(results, [Ok(123), Ok(456)]);
+
+    let mut iter = Nmea0183ParserBuilder::new().build_iter(content_parser, "\r\n\n");
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_build_iter_errors() {
+    let log = "$GPGGA,123*4A\r\n$GPGGA,123*00\r\n$GPGGA,abc\r\n$GPGGA,456*4D\r\n$GPGGA,789";
+
+    let results: Vec<_> = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .build_iter(content_parser, log)
+        .collect();
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[0], Ok(123));
+    assert_eq!(
+        results[1],
+        Err(Error::ChecksumMismatch {
+            expected: 0x4A,
+            found: 0x00
+        })
+    );
+    assert!(results[2].is_err());
+    assert_eq!(results[3], Ok(456));
+    // The last line has no line ending
+    assert!(results[4].is_err());
+}
+
+#[test]
+fn test_build_iter_lf() {
+    let log = "$GPGGA,123*4A\n$GPGGA,456*4D\n";
+
+    let results: Vec<_> = Nmea0183ParserBuilder::new()
+        .line_ending_mode(LineEndingMode::Lf)
+        .build_iter(content_parser, log)
+        .collect();
+    assert_eq!(results, [Ok(123), Ok(456)]);
+
+    // The line endings are checked against the line ending mode
+    let results: Vec<_> = Nmea0183ParserBuilder::new()
+        .build_iter(content_parser, log)
+        .collect();
+    assert!(results.iter().all(Result::is_err));
+}
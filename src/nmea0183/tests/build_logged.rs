@@ -0,0 +1,52 @@
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::digit1,
+    error::{ErrorKind, ParseError},
+    sequence::preceded,
+};
+
+use crate::{Error, IResult, LoggedError, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    preceded(tag("GPGGA,"), digit1).parse(i)
+}
+
+#[test]
+fn test_build_logged_ok() {
+    let mut parser = Nmea0183ParserBuilder::new().build_logged(content_parser);
+
+    let res = parser("$GPGGA,123*4A\r\n");
+    assert_eq!(res, Ok(("", "123")));
+}
+
+#[test]
+fn test_build_logged_content_error() {
+    let mut parser = Nmea0183ParserBuilder::new().build_logged(content_parser);
+
+    let res = parser("$GPGGA,data*6A\r\n");
+    assert_eq!(
+        res,
+        Err(LoggedError {
+            raw: "$GPGGA,data*6A\r\n",
+            error: nom::Err::Error(Error::from_error_kind("data", ErrorKind::Digit)),
+        })
+    );
+}
+
+#[test]
+fn test_build_logged_framing_error() {
+    let mut parser = Nmea0183ParserBuilder::new().build_logged(content_parser);
+
+    let res = parser("$GPGGA,123*00\r\n");
+    assert_eq!(
+        res,
+        Err(LoggedError {
+            raw: "$GPGGA,123*00\r\n",
+            error: nom::Err::Error(Error::ChecksumMismatch {
+                expected: 0x4A,
+                found: 0x00,
+            }),
+        })
+    );
+}
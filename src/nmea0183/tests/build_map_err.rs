@@ -0,0 +1,80 @@
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::digit1,
+    error::{ErrorKind, ParseError},
+    sequence::preceded,
+};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder};
+
+#[derive(Debug, PartialEq)]
+enum AppError<'a> {
+    Checksum { expected: u8, found: u8 },
+    Content(&'a str, ErrorKind),
+    Framing,
+}
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    preceded(tag("GPGGA,"), digit1).parse(i)
+}
+
+fn app_error<'a>(error: Error<&'a str, nom::error::Error<&'a str>>) -> AppError<'a> {
+    match error {
+        Error::ChecksumMismatch { expected, found } => AppError::Checksum { expected, found },
+        Error::ParsingError(error) => AppError::Content(error.input, error.code),
+        _ => AppError::Framing,
+    }
+}
+
+#[test]
+fn test_build_map_err_ok() {
+    let mut parser = Nmea0183ParserBuilder::new().build_map_err(content_parser, app_error);
+
+    assert_eq!(parser("$GPGGA,123*4A\r\n"), Ok(("", "123")));
+}
+
+#[test]
+fn test_build_map_err_errors() {
+    let mut parser = Nmea0183ParserBuilder::new().build_map_err(content_parser, app_error);
+
+    assert_eq!(
+        parser("$GPGGA,123*00\r\n"),
+        Err(nom::Err::Error(AppError::Checksum {
+            expected: 0x4A,
+            found: 0x00
+        }))
+    );
+    assert_eq!(
+        parser("$GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(AppError::Content("data", ErrorKind::Digit)))
+    );
+    assert_eq!(
+        parser("$GPGGA,123*4A"),
+        Err(nom::Err::Error(AppError::Content("*4A", ErrorKind::CrLf)))
+    );
+    assert_eq!(
+        parser("$GPGGA,123"),
+        Err(nom::Err::Error(AppError::Framing))
+    );
+}
+
+#[test]
+fn test_build_map_err_failure() {
+    fn failing_parser(i: &str) -> IResult<&str, &str> {
+        Err(nom::Err::Failure(Error::from_error_kind(
+            i,
+            ErrorKind::Fail,
+        )))
+    }
+
+    let mut parser = Nmea0183ParserBuilder::new().build_map_err(failing_parser, app_error);
+
+    assert_eq!(
+        parser("$GPGGA,123*4A\r\n"),
+        Err(nom::Err::Failure(AppError::Content(
+            "GPGGA,123",
+            ErrorKind::Fail
+        )))
+    );
+}
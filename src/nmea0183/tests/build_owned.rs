@@ -0,0 +1,78 @@
+use alloc::string::String;
+
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::{char, digit1},
+    error::{ErrorKind, ParseError},
+    sequence::preceded,
+};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, String> {
+    preceded(tag("GPGGA"), preceded(char(','), digit1))
+        .map(String::from)
+        .parse(i)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_build_owned_thread() {
+    let mut parser = Nmea0183ParserBuilder::new().build_owned(content_parser);
+
+    let (result, error) = {
+        let buffer = String::from("$GPGGA,123,4*52\r\n$GPGGA,x*02\r\n");
+        let (first, second) = buffer.split_at(17);
+        (parser(first), parser(second))
+    };
+
+    // Neither the result nor the error borrow from the dropped buffer
+    let handle = std::thread::spawn(move || (result, error));
+    let (result, error) = handle.join().unwrap();
+
+    assert_eq!(result, Ok((String::from(",4"), String::from("123"))));
+    assert_eq!(
+        error,
+        Err(nom::Err::Error(Error::from_error_kind(
+            String::from("x"),
+            ErrorKind::Digit
+        )))
+    );
+}
+
+#[test]
+fn test_build_owned_framing_error() {
+    let mut parser = Nmea0183ParserBuilder::new().build_owned(content_parser);
+
+    assert_eq!(
+        parser("$GPGGA,123*00\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x4A,
+            found: 0x00
+        }))
+    );
+    assert_eq!(
+        parser("$GPGGA,é*00\r\n"),
+        Err(nom::Err::Error(Error::NonAscii))
+    );
+}
+
+#[test]
+fn test_build_owned_require_exact() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .require_exact(true)
+        .build_owned(content_parser);
+
+    assert_eq!(
+        parser("$GPGGA,123*4A\r\n"),
+        Ok((String::new(), String::from("123")))
+    );
+    assert_eq!(
+        parser("$GPGGA,123,4*52\r\n"),
+        Err(nom::Err::Error(Error::from_error_kind(
+            String::from(",4"),
+            ErrorKind::Eof
+        )))
+    );
+}
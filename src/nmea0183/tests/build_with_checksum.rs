@@ -0,0 +1,67 @@
+use nom::{Parser, combinator::rest};
+
+use crate::{ChecksumMode, ChecksumStatus, Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_build_with_checksum_present() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .build_with_checksum(content_parser);
+
+    let res = parser("$GPGGA,123*4A\r\n");
+    assert_eq!(
+        res,
+        Ok(("", ("GPGGA,123", ChecksumStatus::Validated(0x4A))))
+    );
+
+    let res = parser("$GPGGA,123*00\r\n");
+    assert_eq!(
+        res,
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x4A,
+            found: 0x00,
+        }))
+    );
+}
+
+#[test]
+fn test_build_with_checksum_absent() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .build_with_checksum(content_parser);
+
+    let res = parser("$GPGGA,123\r\n");
+    assert_eq!(res, Ok(("", ("GPGGA,123", ChecksumStatus::Absent(0x4A)))));
+}
+
+#[test]
+fn test_build_with_checksum_ignored() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Ignore)
+        .build_with_checksum(content_parser);
+
+    let res = parser("$GPGGA,123*4A\r\n");
+    assert_eq!(
+        res,
+        Ok(("", ("GPGGA,123", ChecksumStatus::Validated(0x4A))))
+    );
+
+    let res = parser("$GPGGA,123*99\r\n");
+    assert_eq!(
+        res,
+        Ok((
+            "",
+            (
+                "GPGGA,123",
+                ChecksumStatus::Ignored {
+                    found: 0x99,
+                    computed: 0x4A
+                }
+            )
+        ))
+    );
+}
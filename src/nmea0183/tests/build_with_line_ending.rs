@@ -0,0 +1,52 @@
+use nom::{Parser, combinator::rest};
+
+use crate::{ChecksumMode, IResult, LineEndingMode, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_build_with_line_ending_optional() {
+    for cc in [ChecksumMode::Required, ChecksumMode::Optional] {
+        let mut parser = Nmea0183ParserBuilder::new()
+            .checksum_mode(cc)
+            .line_ending_mode(LineEndingMode::Optional)
+            .build_with_line_ending(content_parser);
+
+        let res = parser("$GPGGA,123*4A\r\n");
+        assert_eq!(res, Ok(("", ("GPGGA,123", Some("\r\n")))), "{cc:?}");
+
+        let res = parser("$GPGGA,123*4A");
+        assert_eq!(res, Ok(("", ("GPGGA,123", None))), "{cc:?}");
+    }
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::Optional)
+        .build_with_line_ending(content_parser);
+
+    assert_eq!(
+        parser("$GPGGA,123\r\n"),
+        Ok(("", ("GPGGA,123", Some("\r\n"))))
+    );
+    assert_eq!(parser("$GPGGA,123"), Ok(("", ("GPGGA,123", None))));
+}
+
+#[test]
+fn test_build_with_line_ending_crlf_or_lf() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::CrlfOrLf)
+        .build_with_line_ending(content_parser);
+
+    assert_eq!(
+        parser("$GPGGA,123*4A\r\n"),
+        Ok(("", ("GPGGA,123", Some("\r\n"))))
+    );
+    assert_eq!(
+        parser("$GPGGA,123*4A\n"),
+        Ok(("", ("GPGGA,123", Some("\n"))))
+    );
+    assert_eq!(parser("$GPGGA,123\n"), Ok(("", ("GPGGA,123", Some("\n")))));
+}
@@ -0,0 +1,66 @@
+use nom::{Parser, combinator::rest};
+
+use crate::{ChecksumMode, Error, IResult, Nmea0183ParserBuilder, SentenceMeta, StartDelimiter};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_build_with_meta_checksum() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .build_with_meta(content_parser);
+
+    let res = parser("$GPGGA,123*4A\r\n");
+    let meta = SentenceMeta {
+        checksum: Some(0x4A),
+        start: '$',
+    };
+    assert_eq!(res, Ok(("", (meta, "GPGGA,123"))));
+
+    let res = parser("$GPGGA,123\r\n");
+    let meta = SentenceMeta {
+        checksum: None,
+        start: '$',
+    };
+    assert_eq!(res, Ok(("", (meta, "GPGGA,123"))));
+
+    let res = parser("$GPGGA,123*00\r\n");
+    assert_eq!(
+        res,
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x4A,
+            found: 0x00,
+        }))
+    );
+}
+
+#[test]
+fn test_build_with_meta_ignored_checksum() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Ignore)
+        .build_with_meta(content_parser);
+
+    // The raw checksum is returned even when it does not match
+    let res = parser("$GPGGA,123*99\r\n");
+    let meta = SentenceMeta {
+        checksum: Some(0x99),
+        start: '$',
+    };
+    assert_eq!(res, Ok(("", (meta, "GPGGA,123"))));
+}
+
+#[test]
+fn test_build_with_meta_start() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .start_delimiter(StartDelimiter::Either)
+        .build_with_meta(content_parser);
+
+    let res = parser("!GPGGA,123*4A\r\n");
+    let meta = SentenceMeta {
+        checksum: Some(0x4A),
+        start: '!',
+    };
+    assert_eq!(res, Ok(("", (meta, "GPGGA,123"))));
+}
@@ -0,0 +1,42 @@
+use nom::{Parser, combinator::rest};
+
+use crate::{ChecksumMode, ChecksumWidth, Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str, checksum: Option<u8>) -> IResult<&str, (&str, Option<u8>)> {
+    rest.map(|content| (content, checksum)).parse(i)
+}
+
+#[test]
+fn test_build_with_raw_checksum() {
+    let mut parser = Nmea0183ParserBuilder::new().build_with_raw_checksum(content_parser);
+
+    // The checksum is passed as-is, whether it matches or not
+    assert_eq!(
+        parser("$GPGGA,123*4A\r\n"),
+        Ok(("", ("GPGGA,123", Some(0x4A))))
+    );
+    assert_eq!(
+        parser("$GPGGA,123*00\r\n"),
+        Ok(("", ("GPGGA,123", Some(0x00))))
+    );
+
+    assert_eq!(
+        parser("$GPGGA,123\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMissing))
+    );
+    assert!(parser("$GPGGA,123*XY\r\n").is_err());
+}
+
+#[test]
+fn test_build_with_raw_checksum_optional() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .checksum_width(ChecksumWidth::OneOrTwo)
+        .build_with_raw_checksum(content_parser);
+
+    assert_eq!(parser("$GPGGA,123\r\n"), Ok(("", ("GPGGA,123", None))));
+    assert_eq!(
+        parser("$GPGGA,123*7\r\n"),
+        Ok(("", ("GPGGA,123", Some(0x07))))
+    );
+}
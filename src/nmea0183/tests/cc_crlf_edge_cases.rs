@@ -0,0 +1,65 @@
+use alloc::format;
+
+use nom::{Err, IResult, Parser, error::ErrorKind};
+
+use crate::nmea0183::{ChecksumMode, LineEndingMode, checksum_crlf};
+
+const MODES: [(ChecksumMode, LineEndingMode, &str); 4] = [
+    (ChecksumMode::Optional, LineEndingMode::Forbidden, ""),
+    (ChecksumMode::Optional, LineEndingMode::Required, "\r\n"),
+    (ChecksumMode::Required, LineEndingMode::Forbidden, ""),
+    (ChecksumMode::Required, LineEndingMode::Required, "\r\n"),
+];
+
+fn assert_error_code(cc: &str, code: ErrorKind) {
+    for (cc_mode, le_mode, le) in MODES {
+        let i = format!("{cc}{le}");
+        let res: IResult<_, _> = checksum_crlf(cc_mode, le_mode).parse(i.as_str());
+
+        match res {
+            Err(Err::Error(error)) => {
+                assert_eq!(error.code, code, "{cc_mode:?}, {le_mode:?}: {i:?}")
+            }
+            res => panic!("{cc_mode:?}, {le_mode:?}: {i:?}: Unexpected result {res:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_checksum_crlf_lowercase() {
+    for (cc_mode, le_mode, le) in MODES {
+        let i = format!("*6a{le}");
+        let res: IResult<_, _> = checksum_crlf(cc_mode, le_mode).parse(i.as_str());
+        assert_eq!(res, Ok(("", Some(0x6A))), "{cc_mode:?}, {le_mode:?}");
+
+        let i = format!("*aF{le}");
+        let res: IResult<_, _> = checksum_crlf(cc_mode, le_mode).parse(i.as_str());
+        assert_eq!(res, Ok(("", Some(0xAF))), "{cc_mode:?}, {le_mode:?}");
+    }
+}
+
+#[test]
+fn test_checksum_crlf_trailing_whitespace() {
+    assert_error_code("*6A ", ErrorKind::Space);
+    assert_error_code("*6A\t", ErrorKind::Space);
+}
+
+#[test]
+fn test_checksum_crlf_leading_whitespace() {
+    assert_error_code("* 6A", ErrorKind::Space);
+}
+
+#[test]
+fn test_checksum_crlf_whitespace_only() {
+    assert_error_code("* ", ErrorKind::Space);
+}
+
+#[test]
+fn test_checksum_crlf_or_lf_whitespace() {
+    for le in ["\r\n", "\n"] {
+        let i = format!("*6A {le}");
+        let res: IResult<_, _> =
+            checksum_crlf(ChecksumMode::Optional, LineEndingMode::CrlfOrLf).parse(i.as_str());
+        assert!(matches!(res, Err(Err::Error(error)) if error.code == ErrorKind::Space));
+    }
+}
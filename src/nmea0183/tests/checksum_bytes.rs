@@ -0,0 +1,29 @@
+use crate::nmea0183::{checksum, checksum_bytes};
+
+#[test]
+fn test_checksum_bytes() {
+    let cases = [
+        "",
+        "A",
+        "GPGGA,data",
+        "GPGGA,123",
+        "GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W",
+        "AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0",
+    ];
+
+    for input in cases {
+        assert_eq!(
+            checksum_bytes(input.as_bytes()),
+            checksum(input).1,
+            "{input:?}"
+        );
+        assert_eq!(
+            checksum_bytes(input.as_bytes()),
+            checksum(input.as_bytes()).1,
+            "{input:?}"
+        );
+    }
+
+    assert_eq!(checksum_bytes(b"GPGGA,data"), 0x6A);
+    assert_eq!(checksum_bytes(&[0xFF, 0x0F]), 0xF0);
+}
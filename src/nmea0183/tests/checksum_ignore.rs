@@ -0,0 +1,55 @@
+use nom::{
+    Parser,
+    combinator::rest,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{ChecksumMode, Error, IResult, LineEndingMode, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_checksum_ignore_mismatch() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Ignore)
+        .line_ending_mode(LineEndingMode::Forbidden)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*99"), Ok(("", "GPGGA,data")));
+    assert_eq!(parser("$GPGGA,data*6A"), Ok(("", "GPGGA,data")));
+    assert_eq!(parser("$GPGGA,data"), Ok(("", "GPGGA,data")));
+}
+
+#[test]
+fn test_checksum_ignore_crlf() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Ignore)
+        .line_ending_mode(LineEndingMode::Required)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*99\r\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(parser("$GPGGA,data\r\n"), Ok(("", "GPGGA,data")));
+    assert!(parser("$GPGGA,data*99").is_err());
+}
+
+#[test]
+fn test_checksum_ignore_malformed() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Ignore)
+        .line_ending_mode(LineEndingMode::Forbidden)
+        .build(content_parser);
+
+    assert_eq!(
+        parser("$GPGGA,data*ZZ"),
+        Err(nom::Err::Error(Error::from_error_kind(
+            "ZZ",
+            ErrorKind::IsA
+        )))
+    );
+    assert_eq!(
+        parser("$GPGGA,data*9"),
+        Err(nom::Err::Error(Error::from_error_kind("9", ErrorKind::Eof)))
+    );
+}
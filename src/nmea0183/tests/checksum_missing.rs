@@ -0,0 +1,60 @@
+use nom::{
+    Parser,
+    combinator::rest,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{ChecksumMode, Error, IResult, LineEndingMode, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_checksum_missing() {
+    for le in [
+        LineEndingMode::Required,
+        LineEndingMode::Forbidden,
+        LineEndingMode::CrlfOrLf,
+        LineEndingMode::Optional,
+    ] {
+        let mut parser = Nmea0183ParserBuilder::new()
+            .checksum_mode(ChecksumMode::Required)
+            .line_ending_mode(le)
+            .build(content_parser);
+
+        for input in ["$GPGGA,data\r\n", "$GPGGA,data"] {
+            assert_eq!(
+                parser(input),
+                Err(nom::Err::Error(Error::ChecksumMissing)),
+                "{le:?} {input:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_checksum_missing_distinct_from_mismatch() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert_eq!(
+        parser("$GPGGA,data*6B\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x6A,
+            found: 0x6B
+        }))
+    );
+    assert_eq!(
+        parser("$GPGGA,data*\r\n"),
+        Err(nom::Err::Error(Error::from_error_kind("", ErrorKind::Eof)))
+    );
+}
+
+#[test]
+fn test_checksum_missing_optional() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data\r\n"), Ok(("", "GPGGA,data")));
+}
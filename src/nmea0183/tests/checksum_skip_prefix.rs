@@ -0,0 +1,46 @@
+use nom::{Parser, combinator::rest};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder, nmea0183::checksum_range};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_checksum_range() {
+    assert_eq!(checksum_range("GPGGA,data", 0), ("GPGGA,data", 0x6A));
+    assert_eq!(checksum_range("GPGGA,data", 1), ("GPGGA,data", 0x2D));
+    assert_eq!(checksum_range("GPGGA,data", 10), ("GPGGA,data", 0));
+    assert_eq!(checksum_range("GPGGA,data", 11), ("GPGGA,data", 0));
+}
+
+#[test]
+fn test_checksum_skip_one_byte() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_skip_prefix(1)
+        .build(content_parser);
+
+    // The skipped byte is still part of the content
+    assert_eq!(parser("$GPGGA,data*2D\r\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(
+        parser("$GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x2D,
+            found: 0x6A
+        }))
+    );
+}
+
+#[test]
+fn test_checksum_skip_default() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(
+        parser("$GPGGA,data*2D\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x6A,
+            found: 0x2D
+        }))
+    );
+}
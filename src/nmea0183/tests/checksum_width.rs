@@ -0,0 +1,61 @@
+use nom::{Parser, combinator::rest, error::ErrorKind};
+
+use crate::{ChecksumMode, ChecksumStatus, ChecksumWidth, Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_checksum_width_two() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert_eq!(parser("$GPGGA,0A*0B\r\n"), Ok(("", "GPGGA,0A")));
+    assert_eq!(
+        parser("$GPGGA,0A*B\r\n"),
+        Err(nom::Err::Error(Error::ParsingError(
+            nom::error::Error::new("B", ErrorKind::Eof)
+        )))
+    );
+}
+
+#[test]
+fn test_checksum_width_one_or_two() {
+    for cc in [ChecksumMode::Required, ChecksumMode::Optional] {
+        let mut parser = Nmea0183ParserBuilder::new()
+            .checksum_mode(cc)
+            .checksum_width(ChecksumWidth::OneOrTwo)
+            .build_with_checksum(content_parser);
+
+        // A single digit is the low nibble of the checksum
+        let res = parser("$GPGGA,0A*B\r\n");
+        assert_eq!(
+            res,
+            Ok(("", ("GPGGA,0A", ChecksumStatus::Validated(0x0B)))),
+            "{cc:?}"
+        );
+
+        let res = parser("$GPGGA,0A*0B\r\n");
+        assert_eq!(
+            res,
+            Ok(("", ("GPGGA,0A", ChecksumStatus::Validated(0x0B)))),
+            "{cc:?}"
+        );
+
+        let res = parser("$GPGGA,0A*A\r\n");
+        assert_eq!(
+            res,
+            Err(nom::Err::Error(Error::ChecksumMismatch {
+                expected: 0x0B,
+                found: 0x0A
+            })),
+            "{cc:?}"
+        );
+
+        let res = parser("$GPGGA,0A*00B\r\n");
+        assert!(res.is_err(), "{cc:?}");
+
+        let res = parser("$GPGGA,0A*\r\n");
+        assert!(res.is_err(), "{cc:?}");
+    }
+}
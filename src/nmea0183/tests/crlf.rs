@@ -1,6 +1,9 @@
 use nom::{Err, IResult, Parser, error::ErrorKind};
 
-use crate::nmea0183::{LineEndingMode, crlf};
+use crate::{
+    ChecksumMode, Nmea0183ParserBuilder,
+    nmea0183::{LineEndingMode, crlf},
+};
 
 #[test]
 fn test_crlf() {
@@ -28,3 +31,50 @@ fn test_crlf() {
         assert_eq!(e.code, ErrorKind::CrLf);
     }
 }
+
+#[test]
+fn test_crlf_or_lf() {
+    let res: IResult<_, _> = crlf(LineEndingMode::CrlfOrLf).parse("12345\r\n");
+    assert_eq!(res, Ok(("12345", ())));
+
+    let res: IResult<_, _> = crlf(LineEndingMode::CrlfOrLf).parse("12345\n");
+    assert_eq!(res, Ok(("12345", ())));
+
+    let res: IResult<_, _> = crlf(LineEndingMode::CrlfOrLf).parse("12345");
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    if let Err::Error(e) = err {
+        assert_eq!(e.code, ErrorKind::CrLf);
+    }
+
+    let res: IResult<_, _> = crlf(LineEndingMode::CrlfOrLf).parse("12345\n\n");
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    if let Err::Error(e) = err {
+        assert_eq!(e.code, ErrorKind::CrLf);
+    }
+}
+
+#[test]
+fn test_crlf_or_lf_mixed_stream() {
+    fn content_parser(i: &str) -> crate::IResult<&str, &str> {
+        Ok(("", i))
+    }
+
+    let stream = "$GPGGA,data*6A\r\n$GPGGA,data*6A\n$GPGGA,data\n$GPGGA,data\r\n";
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::CrlfOrLf)
+        .build(content_parser);
+
+    for sentence in stream.split_inclusive('\n') {
+        let res = parser.parse(sentence);
+        assert_eq!(res, Ok(("", "GPGGA,data")), "Failed: {sentence:?}");
+    }
+
+    for sentence in ["$GPGGA,data*6A", "$GPGGA,data", "$GPGGA,data*99\n"] {
+        let res = parser.parse(sentence);
+        assert!(res.is_err(), "Parsed invalid sentence: {sentence:?}");
+    }
+}
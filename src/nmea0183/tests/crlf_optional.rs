@@ -0,0 +1,56 @@
+use nom::{Err, IResult, Parser, error::ErrorKind};
+
+use crate::{
+    ChecksumMode, Nmea0183ParserBuilder,
+    nmea0183::{LineEndingMode, crlf},
+};
+
+fn content_parser(i: &str) -> crate::IResult<&str, &str> {
+    Ok(("", i))
+}
+
+#[test]
+fn test_crlf_optional() {
+    let res: IResult<_, _> = crlf(LineEndingMode::Optional).parse("12345\r\n");
+    assert_eq!(res, Ok(("12345", ())));
+
+    let res: IResult<_, _> = crlf(LineEndingMode::Optional).parse("12345");
+    assert_eq!(res, Ok(("12345", ())));
+
+    // Anything after the CRLF is still rejected
+    let res: IResult<_, _> = crlf(LineEndingMode::Optional).parse("12345\r\nX");
+    assert!(res.is_err());
+    if let Err::Error(e) = res.unwrap_err() {
+        assert_eq!(e.code, ErrorKind::CrLf);
+    }
+}
+
+#[test]
+fn test_crlf_optional_parser() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Required)
+        .line_ending_mode(LineEndingMode::Optional)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(parser("$GPGGA,data*6A"), Ok(("", "GPGGA,data")));
+    assert!(parser("$GPGGA,data*6A\n").is_err());
+    assert!(parser("$GPGGA,data\r\n").is_err());
+}
+
+#[test]
+fn test_crlf_optional_checksum_optional() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::Optional)
+        .build(content_parser);
+
+    for input in [
+        "$GPGGA,data*6A\r\n",
+        "$GPGGA,data*6A",
+        "$GPGGA,data\r\n",
+        "$GPGGA,data",
+    ] {
+        assert_eq!(parser(input), Ok(("", "GPGGA,data")), "{input:?}");
+    }
+}
@@ -0,0 +1,101 @@
+use alloc::{format, string::ToString};
+
+use nom::{Parser, combinator::rest};
+
+use crate::{
+    ChecksumMode, Error, IResult, LineEndingMode, Nmea0183Encoder, Nmea0183ParserBuilder,
+    format_checksum_into,
+};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_encode_modes() {
+    let cases = [
+        (
+            ChecksumMode::Required,
+            LineEndingMode::Required,
+            "$GPGGA,123*4A\r\n",
+        ),
+        (
+            ChecksumMode::Required,
+            LineEndingMode::Forbidden,
+            "$GPGGA,123*4A",
+        ),
+        (
+            ChecksumMode::Required,
+            LineEndingMode::CrlfOrLf,
+            "$GPGGA,123*4A\r\n",
+        ),
+        (
+            ChecksumMode::Optional,
+            LineEndingMode::Required,
+            "$GPGGA,123\r\n",
+        ),
+        (
+            ChecksumMode::Optional,
+            LineEndingMode::Forbidden,
+            "$GPGGA,123",
+        ),
+    ];
+
+    for (cc, le, expected) in cases {
+        let encoder = Nmea0183Encoder::new()
+            .checksum_mode(cc)
+            .line_ending_mode(le);
+        assert_eq!(encoder.encode("GPGGA,123"), Ok(expected.to_string()));
+    }
+}
+
+#[test]
+fn test_encode_checksum_format() {
+    // Checksums below 0x10 keep their leading zero
+    let encoder = Nmea0183Encoder::new();
+    assert_eq!(encoder.encode("A1"), Ok("$A1*70\r\n".to_string()));
+    assert_eq!(encoder.encode("AA"), Ok("$AA*00\r\n".to_string()));
+    assert_eq!(encoder.encode(""), Ok("$*00\r\n".to_string()));
+}
+
+#[test]
+fn test_encode_non_ascii() {
+    let encoder = Nmea0183Encoder::new();
+    assert_eq!(encoder.encode("GPGGA,°"), Err(Error::NonAscii));
+}
+
+#[test]
+fn test_encode_round_trip() {
+    for cc in [ChecksumMode::Required, ChecksumMode::Optional] {
+        for le in [
+            LineEndingMode::Required,
+            LineEndingMode::Forbidden,
+            LineEndingMode::CrlfOrLf,
+        ] {
+            let sentence = Nmea0183Encoder::new()
+                .checksum_mode(cc)
+                .line_ending_mode(le)
+                .encode("GPZDA,123456.78,29,02,2024,03,00")
+                .unwrap();
+
+            let mut parser = Nmea0183ParserBuilder::new()
+                .checksum_mode(cc)
+                .line_ending_mode(le)
+                .build(content_parser);
+            assert_eq!(
+                parser(&sentence),
+                Ok(("", "GPZDA,123456.78,29,02,2024,03,00")),
+                "{sentence:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_format_checksum_into() {
+    let mut buf = [0; 2];
+
+    for cc in 0..=u8::MAX {
+        assert_eq!(format_checksum_into(&mut buf, cc), format!("{cc:02X}"));
+    }
+}
@@ -0,0 +1,70 @@
+use alloc::string::ToString;
+
+use nom::{Err, IResult, Parser, error::ErrorKind};
+
+use crate::{
+    ChecksumMode, Nmea0183Encoder, Nmea0183ParserBuilder,
+    nmea0183::{LineEndingMode, crlf},
+};
+
+fn content_parser(i: &str) -> crate::IResult<&str, &str> {
+    Ok(("", i))
+}
+
+#[test]
+fn test_crlf_lf() {
+    let res: IResult<_, _> = crlf(LineEndingMode::Lf).parse("12345\n");
+    assert_eq!(res, Ok(("12345", ())));
+
+    let res: IResult<_, _> = crlf(LineEndingMode::Lf).parse("12345\r\n");
+    assert!(res.is_err());
+    if let Err::Error(e) = res.unwrap_err() {
+        assert_eq!(e.code, ErrorKind::CrLf);
+    }
+
+    let res: IResult<_, _> = crlf(LineEndingMode::Lf).parse("12345");
+    assert!(res.is_err());
+    if let Err::Error(e) = res.unwrap_err() {
+        assert_eq!(e.code, ErrorKind::CrLf);
+    }
+}
+
+#[test]
+fn test_lf_parser() {
+    for cc in [ChecksumMode::Required, ChecksumMode::Optional] {
+        let mut parser = Nmea0183ParserBuilder::new()
+            .checksum_mode(cc)
+            .line_ending_mode(LineEndingMode::Lf)
+            .build(content_parser);
+
+        assert_eq!(parser("$GPGGA,data*6A\n"), Ok(("", "GPGGA,data")));
+        assert!(parser("$GPGGA,data*6A\r\n").is_err());
+        assert!(parser("$GPGGA,data*6A").is_err());
+    }
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::Lf)
+        .build(content_parser);
+    assert_eq!(parser("$GPGGA,data\n"), Ok(("", "GPGGA,data")));
+    assert!(parser("$GPGGA,data\r\n").is_err());
+}
+
+#[test]
+fn test_crlf_or_lf_parser() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .line_ending_mode(LineEndingMode::CrlfOrLf)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*6A\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", "GPGGA,data")));
+}
+
+#[test]
+fn test_lf_encoder() {
+    let encoder = Nmea0183Encoder::new().line_ending_mode(LineEndingMode::Lf);
+    assert_eq!(
+        encoder.encode("GPGGA,data"),
+        Ok("$GPGGA,data*6A\n".to_string())
+    );
+}
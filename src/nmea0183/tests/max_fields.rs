@@ -0,0 +1,60 @@
+use alloc::{format, vec, vec::Vec};
+
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::{char, digit0},
+    error::{ErrorKind, ParseError},
+    multi::separated_list0,
+    sequence::preceded,
+};
+
+use crate::{ChecksumMode, Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, Vec<&str>> {
+    preceded(tag("GPGGA,"), separated_list0(char(','), digit0)).parse(i)
+}
+
+#[test]
+fn test_max_fields_ok() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .max_fields(4)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,1,2,3\r\n"), Ok(("", vec!["1", "2", "3"])));
+    assert_eq!(parser("$GPGGA,1\r\n"), Ok(("", vec!["1"])));
+}
+
+#[test]
+fn test_max_fields_comma_bomb() {
+    let content = format!("GPGGA{}", ",".repeat(10_000));
+    let sentence = format!("${content}\r\n");
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .max_fields(4)
+        .build(content_parser);
+
+    assert_eq!(
+        parser(&sentence),
+        Err(nom::Err::Error(Error::from_error_kind(
+            content.as_str(),
+            ErrorKind::TooLarge
+        )))
+    );
+
+    assert!(parser("$GPGGA,1,2,3,4\r\n").is_err());
+}
+
+#[test]
+fn test_max_fields_unlimited() {
+    let sentence = format!("$GPGGA{}\r\n", ",".repeat(1_000));
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .build(content_parser);
+
+    let (_, fields) = parser(&sentence).unwrap();
+    assert_eq!(fields.len(), 1_000);
+}
@@ -0,0 +1,68 @@
+use alloc::{format, string::String};
+
+use nom::{Parser, combinator::rest};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder, checksum_bytes};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+/// Builds a sentence with a valid checksum whose total length is `length`
+fn sentence(length: usize) -> String {
+    let content = format!("GPGGA,{}", "0".repeat(length - 12));
+    format!("${content}*{:02X}\r\n", checksum_bytes(content.as_bytes()))
+}
+
+#[test]
+fn test_max_length_boundary() {
+    let (shorter, exact, longer) = (sentence(81), sentence(82), sentence(83));
+    let mut parser = Nmea0183ParserBuilder::new()
+        .max_length(Nmea0183ParserBuilder::MAX_SENTENCE_LENGTH)
+        .build(content_parser);
+
+    assert_eq!(exact.len(), 82);
+    assert_eq!(parser(&exact), Ok(("", &exact[1..77])));
+    assert!(parser(&shorter).is_ok());
+    assert_eq!(
+        parser(&longer),
+        Err(nom::Err::Error(Error::TooLong {
+            max_length: 82,
+            length: 83
+        }))
+    );
+}
+
+#[test]
+fn test_max_length_custom() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .max_length(16)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(
+        parser("$GPGGA,data1*5B\r\n"),
+        Err(nom::Err::Error(Error::TooLong {
+            max_length: 16,
+            length: 17
+        }))
+    );
+}
+
+#[test]
+fn test_max_length_unlimited() {
+    let input = sentence(1_000);
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert!(parser(&input).is_ok());
+}
+
+#[test]
+fn test_max_length_trim() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .max_length(16)
+        .trim(true)
+        .build(content_parser);
+
+    assert_eq!(parser("  $GPGGA,data*6A\r\n\t"), Ok(("", "GPGGA,data")));
+}
@@ -0,0 +1,47 @@
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::{char, digit1},
+    error::{ErrorKind, ParseError},
+    sequence::preceded,
+};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    preceded(tag("GPGGA"), preceded(char(','), digit1)).parse(i)
+}
+
+#[test]
+fn test_require_exact() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .require_exact(true)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,123*4A\r\n"), Ok(("", "123")));
+    assert_eq!(
+        parser("$GPGGA,123,4*52\r\n"),
+        Err(nom::Err::Error(Error::from_error_kind(
+            ",4",
+            ErrorKind::Eof
+        )))
+    );
+
+    // Errors of the content parser are returned as-is
+    assert_eq!(
+        parser("$GPGGA,x*02\r\n"),
+        Err(nom::Err::Error(Error::from_error_kind(
+            "x",
+            ErrorKind::Digit
+        )))
+    );
+}
+
+#[test]
+fn test_require_exact_disabled() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .require_exact(false)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,123,4*52\r\n"), Ok((",4", "123")));
+}
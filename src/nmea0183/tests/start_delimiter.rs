@@ -0,0 +1,49 @@
+use nom::{
+    Parser,
+    combinator::rest,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder, StartDelimiter};
+
+const AIVDM: &str = "!AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5F\r\n";
+const AIVDM_CONTENT: &str = "AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0";
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_start_delimiter_dollar() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert_eq!(parser("$GPGGA,123*4A\r\n"), Ok(("", "GPGGA,123")));
+    assert_eq!(
+        parser(AIVDM),
+        Err(nom::Err::Error(Error::from_error_kind(
+            AIVDM,
+            ErrorKind::Char
+        )))
+    );
+}
+
+#[test]
+fn test_start_delimiter_bang() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .start_delimiter(StartDelimiter::Bang)
+        .build(content_parser);
+
+    assert_eq!(parser(AIVDM), Ok(("", AIVDM_CONTENT)));
+    assert!(parser("$GPGGA,123*4A\r\n").is_err());
+}
+
+#[test]
+fn test_start_delimiter_either() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .start_delimiter(StartDelimiter::Either)
+        .build(content_parser);
+
+    assert_eq!(parser(AIVDM), Ok(("", AIVDM_CONTENT)));
+    assert_eq!(parser("$GPGGA,123*4A\r\n"), Ok(("", "GPGGA,123")));
+    assert!(parser("#GPGGA,123*4A\r\n").is_err());
+}
@@ -0,0 +1,62 @@
+use nom::{Parser, combinator::rest, error::ErrorKind};
+
+use crate::{ChecksumMode, ChecksumWidth, Error, IResult, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_strict_hex() {
+    for cc in [
+        ChecksumMode::Required,
+        ChecksumMode::Optional,
+        ChecksumMode::Ignore,
+    ] {
+        let mut parser = Nmea0183ParserBuilder::new()
+            .checksum_mode(cc)
+            .strict_hex(true)
+            .build(content_parser);
+
+        assert_eq!(
+            parser("$GPGGA,data*6A\r\n"),
+            Ok(("", "GPGGA,data")),
+            "{cc:?}"
+        );
+        assert_eq!(
+            parser("$GPGGA,data*6a\r\n"),
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("a", ErrorKind::IsA)
+            ))),
+            "{cc:?}"
+        );
+        assert_eq!(
+            parser("$GPGGA,data*6G\r\n"),
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("G", ErrorKind::IsA)
+            ))),
+            "{cc:?}"
+        );
+    }
+}
+
+#[test]
+fn test_strict_hex_one_or_two() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_width(ChecksumWidth::OneOrTwo)
+        .strict_hex(true)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,0A*B\r\n"), Ok(("", "GPGGA,0A")));
+    assert!(parser("$GPGGA,0A*b\r\n").is_err());
+}
+
+#[test]
+fn test_strict_hex_disabled() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .strict_hex(false)
+        .build(content_parser);
+
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", "GPGGA,data")));
+    assert_eq!(parser("$GPGGA,data*6a\r\n"), Ok(("", "GPGGA,data")));
+}
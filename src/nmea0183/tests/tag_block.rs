@@ -0,0 +1,140 @@
+use nom::{
+    Parser,
+    combinator::rest,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{ChecksumMode, Error, IResult, Nmea0183ParserBuilder, StartDelimiter, TagBlock};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_tag_block() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .start_delimiter(StartDelimiter::Either)
+        .tag_block(true)
+        .build_with_tag_block(content_parser);
+
+    let cases = [
+        (
+            "\\s:r003669945,c:1241544035*79\\!GPGGA,data*6A\r\n",
+            TagBlock {
+                source: Some("r003669945"),
+                timestamp: Some(1241544035),
+                ..Default::default()
+            },
+        ),
+        (
+            "\\g:1-2-73874,n:157036,s:r003669946,c:1241544035*49\\$GPGGA,data*6A\r\n",
+            TagBlock {
+                source: Some("r003669946"),
+                timestamp: Some(1241544035),
+                line_count: Some(157036),
+                ..Default::default()
+            },
+        ),
+        (
+            "\\n:12,r:345,t:hello world*43\\$GPGGA,data*6A\r\n",
+            TagBlock {
+                line_count: Some(12),
+                relative_time: Some(345),
+                text: Some("hello world"),
+                ..Default::default()
+            },
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parser(input),
+            Ok(("", (Some(expected), "GPGGA,data"))),
+            "{input:?}"
+        );
+    }
+
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", (None, "GPGGA,data"))));
+}
+
+#[test]
+fn test_tag_block_disabled() {
+    let mut parser = Nmea0183ParserBuilder::new().build_with_tag_block(content_parser);
+
+    assert_eq!(
+        parser("\\s:station,c:1234567890*47\\$GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(Error::from_error_kind(
+            "\\s:station,c:1234567890*47\\$GPGGA,data*6A\r\n",
+            ErrorKind::Char
+        )))
+    );
+    assert_eq!(parser("$GPGGA,data*6A\r\n"), Ok(("", (None, "GPGGA,data"))));
+}
+
+#[test]
+fn test_tag_block_checksum() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .tag_block(true)
+        .build(content_parser);
+
+    assert_eq!(
+        parser("\\s:station,c:1234567890*48\\$GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x47,
+            found: 0x48
+        }))
+    );
+    assert_eq!(
+        parser("\\s:station,c:1234567890\\$GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMissing))
+    );
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .tag_block(true)
+        .build(content_parser);
+
+    assert_eq!(
+        parser("\\s:station,c:1234567890\\$GPGGA,data*6A\r\n"),
+        Ok(("", "GPGGA,data"))
+    );
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Ignore)
+        .tag_block(true)
+        .build(content_parser);
+
+    assert_eq!(
+        parser("\\s:station,c:1234567890*48\\$GPGGA,data*6A\r\n"),
+        Ok(("", "GPGGA,data"))
+    );
+}
+
+#[test]
+fn test_tag_block_invalid() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .tag_block(true)
+        .build(content_parser);
+
+    assert_eq!(
+        parser("\\c:abc*39\\$GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(Error::InvalidField("abc")))
+    );
+    // Unterminated tag block
+    assert!(parser("\\s:station*0E$GPGGA,data*6A\r\n").is_err());
+    // Parameter without a code
+    assert!(parser("\\station*00\\$GPGGA,data*6A\r\n").is_err());
+}
+
+#[test]
+fn test_tag_block_max_length() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .tag_block(true)
+        .max_length(16)
+        .build(content_parser);
+
+    assert_eq!(
+        parser("\\s:station,c:1234567890*47\\$GPGGA,data*6A\r\n"),
+        Ok(("", "GPGGA,data"))
+    );
+}
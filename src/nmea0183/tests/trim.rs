@@ -0,0 +1,73 @@
+use nom::{Parser, combinator::rest};
+
+use crate::{Error, IResult, LineEndingMode, Nmea0183ParserBuilder};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    rest.parse(i)
+}
+
+#[test]
+fn test_trim() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .trim(true)
+        .build(content_parser);
+
+    let cases = [
+        "$GPGGA,data*6A\r\n",
+        "  $GPGGA,data*6A\r\n",
+        "\t $GPGGA,data*6A\r\n",
+        "\r\n$GPGGA,data*6A\r\n",
+        "$GPGGA,data*6A\r\n  ",
+        "  $GPGGA,data*6A\r\n\t",
+    ];
+
+    for input in cases {
+        assert_eq!(parser(input), Ok(("", "GPGGA,data")), "{input:?}");
+    }
+
+    // The line ending is still required
+    assert!(parser("  $GPGGA,data*6A  ").is_err());
+    // Whitespace inside the sentence is not skipped
+    assert!(parser("  $ GPGGA,data*6A\r\n").is_err());
+    assert!(parser("  $GPGGA,data*6A \r\n").is_err());
+    // A sentence made of whitespace only
+    assert!(parser("  \t").is_err());
+}
+
+#[test]
+fn test_trim_without_line_ending() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .trim(true)
+        .line_ending_mode(LineEndingMode::Forbidden)
+        .build(content_parser);
+
+    assert_eq!(parser(" $GPGGA,data*6A \t"), Ok(("", "GPGGA,data")));
+}
+
+#[test]
+fn test_trim_bytes() {
+    fn content_parser(i: &[u8]) -> IResult<&[u8], &[u8]> {
+        rest.parse(i)
+    }
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .trim(true)
+        .build(content_parser);
+
+    assert_eq!(
+        parser(b"  $GPGGA,data*6A\r\n  ".as_slice()),
+        Ok((b"".as_slice(), b"GPGGA,data".as_slice()))
+    );
+}
+
+#[test]
+fn test_trim_disabled() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert_eq!(
+        parser("  $GPGGA,data*6A\r\n"),
+        Err(nom::Err::Error(Error::ParsingError(
+            nom::error::Error::new("  $GPGGA,data*6A\r\n", nom::error::ErrorKind::Char)
+        )))
+    );
+}
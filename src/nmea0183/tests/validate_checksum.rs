@@ -0,0 +1,38 @@
+use nom::error::{ErrorKind, ParseError};
+
+use crate::{Error, validate_checksum};
+
+#[test]
+fn test_validate_checksum_valid() {
+    assert_eq!(validate_checksum("$GPGGA,data*6A\r\n"), Ok(true));
+    assert_eq!(validate_checksum("$GPGGA,data*6A\n"), Ok(true));
+    assert_eq!(validate_checksum("$GPGGA,data*6A"), Ok(true));
+    assert_eq!(validate_checksum("$GPGGA,data*6a"), Ok(true));
+}
+
+#[test]
+fn test_validate_checksum_mismatch() {
+    assert_eq!(validate_checksum("$GPGGA,data*6B\r\n"), Ok(false));
+    assert_eq!(validate_checksum("$GPGGA,date*6A"), Ok(false));
+}
+
+#[test]
+fn test_validate_checksum_errors() {
+    assert_eq!(
+        validate_checksum("GPGGA,data*6A"),
+        Err(Error::from_error_kind("GPGGA,data*6A", ErrorKind::Char))
+    );
+    assert_eq!(
+        validate_checksum("$GPGGA,data\r\n"),
+        Err(Error::ChecksumMissing)
+    );
+    assert_eq!(
+        validate_checksum("$GPGGA,data*ZZ"),
+        Err(Error::from_error_kind("ZZ", ErrorKind::IsA))
+    );
+    assert_eq!(
+        validate_checksum("$GPGGA,data*6"),
+        Err(Error::from_error_kind("6", ErrorKind::Eof))
+    );
+    assert_eq!(validate_checksum("$GPGGA,dätä*6A"), Err(Error::NonAscii));
+}
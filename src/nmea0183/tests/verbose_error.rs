@@ -0,0 +1,100 @@
+use alloc::{vec, vec::Vec};
+
+use nom::{
+    Parser,
+    branch::alt,
+    character::complete::{char, digit1},
+    error::{ContextError, ErrorKind, ParseError, context},
+    sequence::preceded,
+};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder};
+
+/// A minimal verbose error, recording the whole backtrace like nom-language's `VerboseError`
+#[derive(Debug, PartialEq)]
+struct Verbose<I> {
+    errors: Vec<(I, VerboseKind)>,
+}
+
+#[derive(Debug, PartialEq)]
+enum VerboseKind {
+    Context(&'static str),
+    Nom(ErrorKind),
+}
+
+impl<I> ParseError<I> for Verbose<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Verbose {
+            errors: vec![(input, VerboseKind::Nom(kind))],
+        }
+    }
+
+    fn append(input: I, kind: ErrorKind, mut other: Self) -> Self {
+        other.errors.push((input, VerboseKind::Nom(kind)));
+        other
+    }
+}
+
+impl<I> ContextError<I> for Verbose<I> {
+    fn add_context(input: I, ctx: &'static str, mut other: Self) -> Self {
+        other.errors.push((input, VerboseKind::Context(ctx)));
+        other
+    }
+}
+
+fn content_parser(i: &str) -> IResult<&str, &str, Verbose<&str>> {
+    context(
+        "sentence",
+        preceded(
+            context("header", alt((char('A'), char('B')))),
+            preceded(char(','), context("value", digit1)),
+        ),
+    )
+    .parse(i)
+}
+
+#[test]
+fn test_verbose_error_backtrace() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    assert_eq!(parser("$A,123*5D\r\n"), Ok(("", "123")));
+
+    let result = parser("$C,123*5F\r\n");
+    assert_eq!(
+        result,
+        Err(nom::Err::Error(Error::ParsingError(Verbose {
+            errors: vec![
+                ("C,123", VerboseKind::Nom(ErrorKind::Char)),
+                ("C,123", VerboseKind::Nom(ErrorKind::Alt)),
+                ("C,123", VerboseKind::Context("header")),
+                ("C,123", VerboseKind::Context("sentence")),
+            ]
+        })))
+    );
+
+    let result = parser("$A,x*15\r\n");
+    assert_eq!(
+        result,
+        Err(nom::Err::Error(Error::ParsingError(Verbose {
+            errors: vec![
+                ("x", VerboseKind::Nom(ErrorKind::Digit)),
+                ("x", VerboseKind::Context("value")),
+                ("A,x", VerboseKind::Context("sentence")),
+            ]
+        })))
+    );
+}
+
+#[test]
+fn test_verbose_error_framing() {
+    let mut parser = Nmea0183ParserBuilder::new().build(content_parser);
+
+    // Framing errors are not content parsing errors and carry no backtrace
+    assert_eq!(
+        parser("$A,123*5E\r\n"),
+        Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x5D,
+            found: 0x5E
+        }))
+    );
+}
@@ -0,0 +1,116 @@
+use nom::{AsChar, Compare, Input, Offset, Parser, combinator::map_opt, error::ParseError};
+
+use crate::{
+    IResult, NmeaParse,
+    nmea_content::{
+        TimePrecision,
+        parse::{invalid_field, time_with_precision, utc_offset},
+    },
+};
+
+impl<I, E> NmeaParse<I, E> for ::chrono::NaiveTime
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        time_with_precision(TimePrecision::Milliseconds)
+            .map(|time| {
+                let (hour, minute, second, nanosecond) = time.as_hms_nano();
+                // `time::Time` is always a valid time of day
+                ::chrono::NaiveTime::from_hms_nano_opt(
+                    hour as u32,
+                    minute as u32,
+                    second as u32,
+                    nanosecond,
+                )
+                .unwrap()
+            })
+            .parse(i)
+    }
+}
+
+impl<I, E> NmeaParse<I, E> for ::chrono::NaiveDate
+where
+    I: Input + for<'a> Compare<&'a [u8]>,
+    I: Compare<&'static str>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(start: I) -> IResult<I, Self, E> {
+        let (i, date) = time::Date::parse(start.clone())?;
+
+        let date =
+            ::chrono::NaiveDate::from_ymd_opt(date.year(), date.month() as u32, date.day() as u32)
+                .ok_or_else(|| invalid_field(start.take(6)))?;
+
+        Ok((i, date))
+    }
+}
+
+impl<I, E> NmeaParse<I, E> for ::chrono::FixedOffset
+where
+    I: Input + Offset,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        map_opt(utc_offset, |offset: Option<time::UtcOffset>| {
+            ::chrono::FixedOffset::east_opt(offset?.whole_seconds())
+        })
+        .parse(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_parse_naive_time() {
+        let result: IResult<_, _> = ::chrono::NaiveTime::parse("123456.78");
+        let expected = ::chrono::NaiveTime::from_hms_milli_opt(12, 34, 56, 780).unwrap();
+        assert_eq!(result, Ok(("", expected)));
+
+        let result: IResult<_, _> = ::chrono::NaiveTime::parse("123456");
+        let expected = ::chrono::NaiveTime::from_hms_opt(12, 34, 56).unwrap();
+        assert_eq!(result, Ok(("", expected)));
+
+        let result: IResult<_, ::chrono::NaiveTime> = ::chrono::NaiveTime::parse("246000.00,A");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::InvalidField("246000.00")))
+        );
+    }
+
+    #[test]
+    fn test_parse_naive_date() {
+        let cases = [("230394", 1994, 3, 23), ("290224", 2024, 2, 29)];
+
+        for (input, year, month, day) in cases {
+            let result: IResult<_, _> = ::chrono::NaiveDate::parse(input);
+            let expected = ::chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            assert_eq!(result, Ok(("", expected)), "{input:?}");
+        }
+
+        let result: IResult<_, ::chrono::NaiveDate> = ::chrono::NaiveDate::parse("310225,A");
+        assert_eq!(result, Err(nom::Err::Error(Error::InvalidField("310225"))));
+    }
+
+    #[test]
+    fn test_parse_fixed_offset() {
+        let result: IResult<_, _> = ::chrono::FixedOffset::parse("-03,30");
+        let expected = ::chrono::FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+        assert_eq!(result, Ok(("", expected)));
+
+        let result: IResult<_, _> = ::chrono::FixedOffset::parse("02,00");
+        let expected = ::chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!(result, Ok(("", expected)));
+
+        let result: IResult<_, ::chrono::FixedOffset> = ::chrono::FixedOffset::parse(",");
+        assert!(result.is_err());
+    }
+}
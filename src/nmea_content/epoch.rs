@@ -0,0 +1,180 @@
+use alloc::{vec, vec::Vec};
+
+use crate::nmea_content::NmeaSentence;
+
+/// All the sentences emitted by a receiver for one fix epoch
+#[derive(Debug, Clone, PartialEq)]
+pub struct GnssEpoch {
+    /// Fix time shared by the sentences of the epoch, [`None`] if none of them has a time
+    pub time: Option<time::Time>,
+    /// Sentences of the epoch, in reception order
+    pub sentences: Vec<NmeaSentence>,
+}
+
+/// Groups consecutive sentences sharing the same fix time into [`GnssEpoch`]s
+///
+/// GNSS receivers emit a burst of sentences per fix epoch, e.g. `GGA`, `RMC`, `GSA` and
+/// `GSV`, which is the natural unit for downstream fusion. The grouper buffers the
+/// sentences and emits the buffered epoch once a sentence with a different fix time
+/// is received.
+///
+/// Sentences without a fix time, such as `GSA` and `GSV`, or with an empty time field,
+/// are attached to the current epoch.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult, NmeaParse,
+///     nmea_content::{EpochGrouper, NmeaSentence},
+/// };
+///
+/// let mut grouper = EpochGrouper::new();
+///
+/// let sentences = [
+///     "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+///     "GPZDA,123519,04,07,2025,,",
+///     "GPGGA,123520,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+/// ];
+/// let mut epochs = Vec::new();
+/// for input in sentences {
+///     let result: IResult<_, _> = NmeaSentence::parse(input);
+///     epochs.extend(grouper.push(result.unwrap().1));
+/// }
+/// epochs.extend(grouper.flush());
+///
+/// assert_eq!(epochs.len(), 2);
+/// assert_eq!(epochs[0].sentences.len(), 2);
+/// assert_eq!(epochs[1].sentences.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EpochGrouper {
+    current: Option<GnssEpoch>,
+}
+
+impl EpochGrouper {
+    /// Creates a grouper with no buffered epoch
+    pub fn new() -> Self {
+        EpochGrouper { current: None }
+    }
+
+    /// Adds a sentence to the current epoch.
+    ///
+    /// Returns the previous epoch if the sentence has a fix time different from the
+    /// one of the current epoch, in which case the sentence starts a new epoch.
+    pub fn push(&mut self, sentence: NmeaSentence) -> Option<GnssEpoch> {
+        let time = fix_time(&sentence);
+
+        let completed = match &mut self.current {
+            Some(epoch) if time.is_none() || epoch.time.is_none_or(|t| Some(t) == time) => {
+                epoch.time = epoch.time.or(time);
+                epoch.sentences.push(sentence);
+                return None;
+            }
+            current => current.take(),
+        };
+
+        self.current = Some(GnssEpoch {
+            time,
+            sentences: vec![sentence],
+        });
+        completed
+    }
+
+    /// Returns the buffered epoch, if any, e.g. once the input is exhausted
+    pub fn flush(&mut self) -> Option<GnssEpoch> {
+        self.current.take()
+    }
+
+    /// Returns the epoch currently being buffered
+    pub fn current(&self) -> Option<&GnssEpoch> {
+        self.current.as_ref()
+    }
+}
+
+/// Returns the fix time of the sentence, if its type carries one and the field is not empty
+fn fix_time(sentence: &NmeaSentence) -> Option<time::Time> {
+    match sentence {
+        NmeaSentence::GGA(gga) => gga.fix_time,
+        NmeaSentence::GLL(gll) => gll.fix_time,
+        NmeaSentence::RMC(rmc) => rmc.fix_time,
+        NmeaSentence::ZDA(zda) => zda.time,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IResult, NmeaParse,
+        nmea_content::{GGA, GSV, RMC},
+    };
+
+    fn gga(time: Option<time::Time>) -> NmeaSentence {
+        NmeaSentence::GGA(GGA {
+            fix_time: time,
+            ..Default::default()
+        })
+    }
+
+    fn rmc(time: Option<time::Time>) -> NmeaSentence {
+        NmeaSentence::RMC(RMC {
+            fix_time: time,
+            ..Default::default()
+        })
+    }
+
+    fn gsv(input: &str) -> NmeaSentence {
+        let result: IResult<_, _> = GSV::parse(input);
+        NmeaSentence::GSV(result.unwrap().1)
+    }
+
+    #[test]
+    fn test_epoch_grouper() {
+        let first = time::Time::from_hms(12, 35, 19).ok();
+        let second = time::Time::from_hms(12, 35, 20).ok();
+        let mut grouper = EpochGrouper::new();
+
+        // GGA + RMC + GSV burst
+        assert_eq!(grouper.push(gga(first)), None);
+        assert_eq!(grouper.push(rmc(first)), None);
+        assert_eq!(grouper.push(gsv("2,1,05,01,60,150,45,")), None);
+        assert_eq!(grouper.push(gsv("2,2,05,05,45,120,38,")), None);
+        assert_eq!(grouper.current().unwrap().sentences.len(), 4);
+
+        // The next epoch completes the burst
+        let epoch = grouper.push(gga(second)).unwrap();
+        assert_eq!(epoch.time, first);
+        assert_eq!(
+            epoch.sentences,
+            [
+                gga(first),
+                rmc(first),
+                gsv("2,1,05,01,60,150,45,"),
+                gsv("2,2,05,05,45,120,38,")
+            ]
+        );
+
+        // Sentences with an empty time field are attached to the current epoch
+        assert_eq!(grouper.push(rmc(None)), None);
+        let epoch = grouper.flush().unwrap();
+        assert_eq!(epoch.time, second);
+        assert_eq!(epoch.sentences, [gga(second), rmc(None)]);
+        assert_eq!(grouper.flush(), None);
+    }
+
+    #[test]
+    fn test_epoch_grouper_untimed_start() {
+        let time = time::Time::from_hms(12, 35, 19).ok();
+        let mut grouper = EpochGrouper::new();
+
+        // An epoch started by untimed sentences takes the time of its first timed sentence
+        assert_eq!(grouper.push(gsv("1,1,01,01,60,150,45,")), None);
+        assert_eq!(grouper.push(gga(time)), None);
+
+        let epoch = grouper.flush().unwrap();
+        assert_eq!(epoch.time, time);
+        assert_eq!(epoch.sentences.len(), 2);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::nmea_content::Location;
+
+/// Mean Earth radius in meters (IUGG)
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+impl Location {
+    /// Returns the great-circle distance to another location in meters.
+    ///
+    /// Uses the haversine formula on a spherical Earth, which is within 0.5% of the
+    /// ellipsoidal distance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::Location;
+    ///
+    /// let origin = Location {
+    ///     latitude: 0.0,
+    ///     longitude: 0.0,
+    /// };
+    /// let east = Location {
+    ///     latitude: 0.0,
+    ///     longitude: 1.0,
+    /// };
+    ///
+    /// assert!((origin.distance_to(&east) - 111_195.08).abs() < 0.01);
+    /// ```
+    pub fn distance_to(&self, other: &Location) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    /// Returns the initial great-circle bearing to another location in degrees true (0-360).
+    ///
+    /// The bearing changes along the great circle, so this is the course to steer at the
+    /// start of the leg only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::Location;
+    ///
+    /// let origin = Location {
+    ///     latitude: 0.0,
+    ///     longitude: 0.0,
+    /// };
+    /// let east = Location {
+    ///     latitude: 0.0,
+    ///     longitude: 1.0,
+    /// };
+    ///
+    /// assert!((origin.bearing_to(&east) - 90.0).abs() < 1e-9);
+    /// ```
+    pub fn bearing_to(&self, other: &Location) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let dlon = (other.longitude - self.longitude).to_radians();
+
+        let y = dlon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(latitude: f64, longitude: f64) -> Location {
+        Location {
+            latitude,
+            longitude,
+        }
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let origin = location(0.0, 0.0);
+        let one_degree = EARTH_RADIUS * core::f64::consts::PI / 180.0;
+
+        assert_eq!(origin.distance_to(&origin), 0.0);
+        assert!((origin.distance_to(&location(0.0, 1.0)) - one_degree).abs() < 1e-6);
+        assert!((origin.distance_to(&location(1.0, 0.0)) - one_degree).abs() < 1e-6);
+        assert!((origin.distance_to(&location(0.0, -90.0)) - 90.0 * one_degree).abs() < 1e-6);
+
+        // Antipodal points are half a circumference apart
+        let antipode = location(0.0, 180.0);
+        assert!((origin.distance_to(&antipode) - 180.0 * one_degree).abs() < 1e-6);
+
+        // Across the antimeridian
+        let west = location(0.0, 179.5);
+        let east = location(0.0, -179.5);
+        assert!((west.distance_to(&east) - one_degree).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distance_to_reference() {
+        // Paris to London, about 343.9km
+        let paris = location(48.8566, 2.3522);
+        let london = location(51.5074, -0.1278);
+        let distance = paris.distance_to(&london);
+        assert!((distance - 343_900.0).abs() < 500.0, "{distance}");
+        assert_eq!(distance, london.distance_to(&paris));
+    }
+
+    #[test]
+    fn test_bearing_to() {
+        let origin = location(0.0, 0.0);
+
+        assert!((origin.bearing_to(&location(1.0, 0.0)) - 0.0).abs() < 1e-9);
+        assert!((origin.bearing_to(&location(0.0, 1.0)) - 90.0).abs() < 1e-9);
+        assert!((origin.bearing_to(&location(-1.0, 0.0)) - 180.0).abs() < 1e-9);
+        assert!((origin.bearing_to(&location(0.0, -1.0)) - 270.0).abs() < 1e-9);
+
+        // Across the antimeridian the shortest way is east
+        let bearing = location(0.0, 179.5).bearing_to(&location(0.0, -179.5));
+        assert!((bearing - 90.0).abs() < 1e-9, "{bearing}");
+
+        // Due east at 60°N starts north of east along the great circle
+        let bearing = location(60.0, 0.0).bearing_to(&location(60.0, 10.0));
+        assert!(bearing > 0.0 && bearing < 90.0, "{bearing}");
+    }
+}
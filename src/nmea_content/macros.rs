@@ -0,0 +1,67 @@
+/// Declares a single-character selector enum.
+///
+/// Each variant is bound to the character that selects it, and the macro derives
+/// [`NmeaParse`](crate::NmeaParse) with the matching `one_of` set and per-variant
+/// selectors, so the set and the variants cannot drift apart. A `From<Name> for char`
+/// implementation is generated as well, mapping every variant back to its character,
+/// e.g. for re-encoding.
+///
+/// Attributes, including doc comments and derives, are forwarded to the enum and its
+/// variants. The generated parser refers to `nom`, which must be a dependency of the
+/// calling crate, as with [`NmeaParse`](crate::NmeaParse) derives in general.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, NmeaParse, one_of_enum};
+///
+/// one_of_enum! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     /// Direction of travel
+///     pub enum Heading {
+///         /// N - Northbound
+///         'N' => North,
+///         /// S - Southbound
+///         'S' => South,
+///     }
+/// }
+///
+/// let result: IResult<_, _> = Heading::parse("S,rest");
+/// assert_eq!(result, Ok((",rest", Heading::South)));
+/// assert_eq!(char::from(Heading::North), 'N');
+///
+/// let result: IResult<_, _> = Heading::parse("E");
+/// assert!(result.is_err());
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-content")))]
+macro_rules! one_of_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $selector:literal => $variant:ident
+            ),+ $(,)?
+        }
+    ) => {
+        #[derive($crate::NmeaParse)]
+        #[nmea(selector(nom::character::complete::one_of(concat!($($selector),+))))]
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                #[nmea(selector($selector))]
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl ::core::convert::From<$name> for char {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $selector,)+
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,305 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::nmea_content::{Location, NmeaSentence};
+
+/// A single numeric value of a sentence, flattened for time-series ingestion
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    /// Name of the measured field, e.g. `altitude`
+    pub name: &'static str,
+    /// Value of the field
+    pub value: f64,
+    /// Unit of the value, e.g. `m` or `kn`, [`None`] for counts and ratios
+    pub unit: Option<&'static str>,
+}
+
+/// Meters
+const METERS: Option<&str> = Some("m");
+/// Degrees
+const DEGREES: Option<&str> = Some("deg");
+/// Knots
+const KNOTS: Option<&str> = Some("kn");
+/// Nautical miles
+const NAUTICAL_MILES: Option<&str> = Some("nmi");
+/// Seconds
+const SECONDS: Option<&str> = Some("s");
+
+/// Collects the measurements of a sentence, skipping the empty fields
+#[derive(Default)]
+struct Measurements(Vec<Measurement>);
+
+impl Measurements {
+    fn push<T: Into<f64>>(
+        &mut self,
+        name: &'static str,
+        value: Option<T>,
+        unit: Option<&'static str>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            self.0.push(Measurement {
+                name,
+                value: value.into(),
+                unit,
+            });
+        }
+        self
+    }
+
+    fn location(&mut self, location: Option<&Location>) -> &mut Self {
+        self.push("latitude", location.map(|l| l.latitude), DEGREES)
+            .push("longitude", location.map(|l| l.longitude), DEGREES)
+    }
+}
+
+impl NmeaSentence {
+    /// Flattens the sentence into its numeric fields, with their names and units.
+    ///
+    /// Empty fields are skipped, as are non-numeric fields such as times, dates and
+    /// status indicators. This is an ingestion adapter for time-series databases and
+    /// telemetry systems, which store each field as a separate measurement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{
+    ///     IResult, NmeaParse,
+    ///     nmea_content::{Measurement, NmeaSentence},
+    /// };
+    ///
+    /// let result: IResult<_, _> = NmeaSentence::parse("GPDBT,12.3,f,3.75,M,2.05,F");
+    /// let (_, sentence) = result.unwrap();
+    ///
+    /// assert_eq!(
+    ///     sentence.measurements(),
+    ///     vec![Measurement {
+    ///         name: "water_depth",
+    ///         value: 3.75,
+    ///         unit: Some("m"),
+    ///     }]
+    /// );
+    /// ```
+    pub fn measurements(&self) -> Vec<Measurement> {
+        let mut m = Measurements::default();
+
+        match self {
+            NmeaSentence::BWC(bwc) => {
+                m.push("bearing_true", bwc.bearing_true, DEGREES)
+                    .push("bearing_magnetic", bwc.bearing_magnetic, DEGREES)
+                    .push("distance", bwc.distance, NAUTICAL_MILES);
+            }
+            NmeaSentence::BWR(bwr) => {
+                m.push("bearing_true", bwr.bearing_true, DEGREES)
+                    .push("bearing_magnetic", bwr.bearing_magnetic, DEGREES)
+                    .push("distance", bwr.distance, NAUTICAL_MILES);
+            }
+            NmeaSentence::DBT(dbt) => {
+                m.push("water_depth", dbt.water_depth, METERS);
+            }
+            NmeaSentence::DPT(dpt) => {
+                m.push("water_depth", dpt.water_depth, METERS).push(
+                    "offset_from_transducer",
+                    dpt.offset_from_transducer,
+                    METERS,
+                );
+                #[cfg(feature = "nmea-v3-0")]
+                m.push("max_range_scale", dpt.max_range_scale, METERS);
+            }
+            NmeaSentence::GGA(gga) => {
+                m.location(gga.location.as_ref())
+                    .push("satellite_count", gga.satellite_count, None)
+                    .push("hdop", gga.hdop, None)
+                    .push("altitude", gga.altitude, METERS)
+                    .push("geoidal_separation", gga.geoidal_separation, METERS)
+                    .push(
+                        "age_of_dgps",
+                        gga.age_of_dgps.map(|age| age.as_secs_f64()),
+                        SECONDS,
+                    );
+            }
+            NmeaSentence::GLL(gll) => {
+                m.location(gll.location.as_ref());
+            }
+            NmeaSentence::GSA(gsa) => {
+                m.push("satellite_count", Some(gsa.fix_sats_prn.len() as u32), None)
+                    .push("pdop", gsa.pdop, None)
+                    .push("hdop", gsa.hdop, None)
+                    .push("vdop", gsa.vdop, None);
+            }
+            NmeaSentence::GSV(gsv) => {
+                m.push("satellites_in_view", Some(gsv.satellites_in_view), None);
+            }
+            NmeaSentence::RMC(rmc) => {
+                m.location(rmc.location.as_ref())
+                    .push("speed_over_ground", rmc.speed_over_ground, KNOTS)
+                    .push("course_over_ground", rmc.course_over_ground, DEGREES)
+                    .push("magnetic_variation", rmc.magnetic_variation, DEGREES);
+            }
+            NmeaSentence::VBW(vbw) => {
+                m.push(
+                    "water_speed_longitudinal",
+                    vbw.water_speed_longitudinal,
+                    KNOTS,
+                )
+                .push("water_speed_transverse", vbw.water_speed_transverse, KNOTS)
+                .push(
+                    "ground_speed_longitudinal",
+                    vbw.ground_speed_longitudinal,
+                    KNOTS,
+                )
+                .push(
+                    "ground_speed_transverse",
+                    vbw.ground_speed_transverse,
+                    KNOTS,
+                );
+                #[cfg(feature = "nmea-v4-11")]
+                m.push(
+                    "stern_water_speed_transverse",
+                    vbw.stern_water_speed_transverse,
+                    KNOTS,
+                )
+                .push(
+                    "stern_ground_speed_transverse",
+                    vbw.stern_ground_speed_transverse,
+                    KNOTS,
+                );
+            }
+            NmeaSentence::VTG(vtg) => {
+                m.push(
+                    "course_over_ground_true",
+                    vtg.course_over_ground_true,
+                    DEGREES,
+                )
+                .push(
+                    "course_over_ground_magnetic",
+                    vtg.course_over_ground_magnetic,
+                    DEGREES,
+                )
+                .push("speed_over_ground", vtg.speed_over_ground, KNOTS);
+            }
+            NmeaSentence::WPL(wpl) => {
+                // The location of a waypoint is not the position of the receiver
+                let location = wpl.location.as_ref();
+                m.push("waypoint_latitude", location.map(|l| l.latitude), DEGREES)
+                    .push("waypoint_longitude", location.map(|l| l.longitude), DEGREES);
+            }
+            NmeaSentence::ZDA(zda) => {
+                m.push(
+                    "utc_offset",
+                    zda.utc_offset.map(|offset| offset.whole_seconds()),
+                    SECONDS,
+                );
+            }
+            // Almanac data describes the orbit of a satellite, not the receiver
+            NmeaSentence::ALM(_) => {}
+            // Routes only list waypoint identifiers
+            NmeaSentence::RTE(_) => {}
+            // The content of proprietary sentences is unknown
+            NmeaSentence::Proprietary(_) => {}
+        }
+
+        m.0
+    }
+}
+
+impl From<&NmeaSentence> for Vec<Measurement> {
+    fn from(value: &NmeaSentence) -> Self {
+        value.measurements()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IResult, NmeaParse};
+
+    fn measurements(content: &str) -> Vec<(&'static str, f64, Option<&'static str>)> {
+        let result: IResult<_, _> = NmeaSentence::parse(content);
+        let (_, sentence) = result.unwrap();
+
+        Vec::from(&sentence)
+            .into_iter()
+            .map(|m| (m.name, m.value, m.unit))
+            .collect()
+    }
+
+    #[test]
+    fn test_gga_measurements() {
+        let result = measurements(
+            "GPGGA,001043.00,4404.14036,N,12118.85961,W,2,12,0.98,1113.0,M,-21.3,M,0.5,0001",
+        );
+
+        assert_eq!(
+            result,
+            vec![
+                ("latitude", 44.0 + 4.14036 / 60.0, Some("deg")),
+                ("longitude", -(121.0 + 18.85961 / 60.0), Some("deg")),
+                ("satellite_count", 12.0, None),
+                ("hdop", 0.98f32 as f64, None),
+                ("altitude", 1113.0, Some("m")),
+                ("geoidal_separation", -21.3f32 as f64, Some("m")),
+                ("age_of_dgps", 0.5, Some("s")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vtg_measurements() {
+        #[cfg(not(feature = "nmea-v2-3"))]
+        let content = "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K";
+        #[cfg(feature = "nmea-v2-3")]
+        let content = "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,A";
+
+        assert_eq!(
+            measurements(content),
+            vec![
+                ("course_over_ground_true", 54.7f32 as f64, Some("deg")),
+                ("course_over_ground_magnetic", 34.4f32 as f64, Some("deg")),
+                ("speed_over_ground", 5.5, Some("kn")),
+            ]
+        );
+
+        #[cfg(not(feature = "nmea-v2-3"))]
+        let content = "GPVTG,,T,,M,005.5,N,010.2,K";
+        #[cfg(feature = "nmea-v2-3")]
+        let content = "GPVTG,,T,,M,005.5,N,010.2,K,A";
+
+        assert_eq!(
+            measurements(content),
+            vec![("speed_over_ground", 5.5, Some("kn"))]
+        );
+    }
+
+    #[test]
+    fn test_bwc_measurements() {
+        #[cfg(not(feature = "nmea-v2-3"))]
+        let content = "GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM";
+        #[cfg(feature = "nmea-v2-3")]
+        let content = "GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM,A";
+
+        assert_eq!(
+            measurements(content),
+            vec![
+                ("bearing_true", 213.8f32 as f64, Some("deg")),
+                ("bearing_magnetic", 218.0, Some("deg")),
+                ("distance", 4.6f32 as f64, Some("nmi")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_route_measurements() {
+        assert_eq!(
+            measurements("GPWPL,4917.16,N,12310.64,W,003"),
+            vec![
+                ("waypoint_latitude", 49.0 + 17.16 / 60.0, Some("deg")),
+                ("waypoint_longitude", -(123.0 + 10.64 / 60.0), Some("deg")),
+            ]
+        );
+
+        assert!(measurements("GPRTE,2,1,c,0,W3IWI,DRIVWY,32CEDR").is_empty());
+    }
+}
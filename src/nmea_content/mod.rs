@@ -1,4 +1,36 @@
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "alloc")]
+mod epoch;
+#[cfg(feature = "geo")]
+mod geo;
+mod macros;
+#[cfg(feature = "alloc")]
+mod measurement;
 mod parse;
+pub mod parsers;
 mod sentences;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "alloc"))))]
+pub mod tagged;
+#[cfg(feature = "std")]
+mod tracker;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "utm")]
+mod utm;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use epoch::{EpochGrouper, GnssEpoch};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use measurement::Measurement;
+pub use parse::time_with_precision;
 pub use sentences::*;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use tracker::{SatelliteTracker, TrackedSatellite};
+#[cfg(feature = "utm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utm")))]
+pub use utm::UtmCoord;
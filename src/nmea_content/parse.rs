@@ -1,15 +1,46 @@
 use nom::{
     AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser, ToUsize,
     branch::alt,
-    bytes::complete::{tag, take},
-    character::complete::{char, one_of},
+    bytes::complete::{tag, take, take_till1},
+    character::complete::{char, digit0, hex_digit0, one_of, satisfy},
     combinator::{opt, value},
     error::ParseError,
-    sequence::separated_pair,
+    sequence::{preceded, separated_pair},
 };
 
-use crate::{Error, IResult, NmeaParse, nmea_content::Location};
+use core::ops::Neg;
 
+use crate::{
+    Error, IResult, NmeaParse,
+    nmea_content::{Location, NmeaTime, TimePrecision},
+};
+
+/// Returns an [`Error::InvalidField`] error for the given field input.
+///
+/// Content parsers use this when a field is well-formed but out of range, e.g. a month of
+/// `13`, so that the error identifies the offending field rather than a generic
+/// [`nom::error::ErrorKind::Verify`].
+pub(crate) fn invalid_field<I, E>(i: I) -> nom::Err<Error<I, E>> {
+    nom::Err::Error(Error::InvalidField(i))
+}
+
+/// Parses a `x.x,u` value followed by its unit field.
+///
+/// Returns [`None`] if either the value or the unit field is empty, so that a value
+/// without its unit is never misinterpreted.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::with_unit};
+/// use nom::Parser;
+///
+/// let result: IResult<_, Option<f32>> = with_unit('M').parse("545.4,M");
+/// assert_eq!(result, Ok(("", Some(545.4))));
+///
+/// let result: IResult<_, Option<f32>> = with_unit('M').parse("545.4,");
+/// assert_eq!(result, Ok(("", None)));
+/// ```
 pub fn with_unit<I, E, T>(unit: char) -> impl Parser<I, Output = Option<T>, Error = Error<I, E>>
 where
     T: NmeaParse<I, E>,
@@ -21,6 +52,109 @@ where
         .map(|(value, unit)| unit.and(value))
 }
 
+/// Parses a `x.x,a` value followed by its direction field, and applies the sign of the
+/// direction.
+///
+/// The value is negated if the direction is `negative`, and left as-is if it is `positive`.
+/// Any other direction is an error. This is the convention of the hemisphere of latitudes
+/// (`N`/`S`) and longitudes (`E`/`W`), and of the direction of magnetic variations.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, NmeaParse, nmea_content::parsers::with_sign};
+/// use nom::Parser;
+///
+/// let result: IResult<_, f32> = with_sign(f32::parse, 'E', 'W').parse("003.1,W");
+/// assert_eq!(result, Ok(("", -3.1)));
+///
+/// let result: IResult<_, f32> = with_sign(f32::parse, 'E', 'W').parse("003.1,E");
+/// assert_eq!(result, Ok(("", 3.1)));
+///
+/// let result: IResult<_, f32> = with_sign(f32::parse, 'E', 'W').parse("003.1,N");
+/// assert!(result.is_err());
+/// ```
+pub fn with_sign<I, E, T, F>(
+    parser: F,
+    positive: char,
+    negative: char,
+) -> impl Parser<I, Output = T, Error = Error<I, E>>
+where
+    F: Parser<I, Output = T, Error = Error<I, E>>,
+    T: Neg<Output = T>,
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    separated_pair(
+        parser,
+        char(','),
+        satisfy(move |dir| dir == positive || dir == negative),
+    )
+    .map(
+        move |(value, dir)| {
+            if dir == negative { -value } else { value }
+        },
+    )
+}
+
+/// Parses a hexadecimal field, such as the orbital parameters of `ALM` sentences, into an
+/// integer of any width.
+///
+/// Returns [`None`] if the field is empty, and an [`Error::InvalidField`] error if the value
+/// does not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::hex};
+///
+/// let result: IResult<_, Option<u16>> = hex("441d,4e");
+/// assert_eq!(result, Ok((",4e", Some(0x441D))));
+///
+/// let result: IResult<_, Option<u16>> = hex(",4e");
+/// assert_eq!(result, Ok((",4e", None)));
+///
+/// let result: IResult<_, Option<u8>> = hex("441d,4e");
+/// assert!(result.is_err());
+/// ```
+pub fn hex<I, E, T>(i: I) -> IResult<I, Option<T>, E>
+where
+    T: TryFrom<u32>,
+    I: Input + AsBytes,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    let (rest, digits) = hex_digit0(i)?;
+    if digits.input_len() == 0 {
+        return Ok((rest, None));
+    }
+
+    let value = digits.as_bytes().iter().try_fold(0u32, |value, &digit| {
+        let digit = (digit as char).to_digit(16)?;
+        value.checked_mul(16)?.checked_add(digit)
+    });
+
+    match value.and_then(|value| T::try_from(value).ok()) {
+        Some(value) => Ok((rest, Some(value))),
+        None => Err(invalid_field(digits)),
+    }
+}
+
+/// Parses a value from exactly `count` characters.
+///
+/// This is used for fixed-width components packed in a single field, such as the
+/// `ddmm.mm` latitude.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::with_take};
+/// use nom::Parser;
+///
+/// let result: IResult<_, u8> = with_take(2u8).parse("0712.5");
+/// assert_eq!(result, Ok(("12.5", 7)));
+/// ```
 pub fn with_take<I, E, T, C>(count: C) -> impl Parser<I, Output = T, Error = Error<I, E>>
 where
     T: NmeaParse<I, E>,
@@ -32,6 +166,24 @@ where
     take(count).and_then(T::parse)
 }
 
+/// Parses a `llll.ll,a,yyyyy.yy,a` latitude and longitude pair into a [`Location`].
+///
+/// The latitude is negative in the southern hemisphere and the longitude is negative
+/// west of the prime meridian. Returns [`None`] if all four fields are empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::location};
+///
+/// let result: IResult<_, _> = location("4916.45,N,12311.12,W");
+/// let parsed = result.unwrap().1.unwrap();
+/// assert!((parsed.latitude - 49.274166).abs() < 1e-6);
+/// assert!((parsed.longitude + 123.185333).abs() < 1e-6);
+///
+/// let result: IResult<_, _> = location(",,,");
+/// assert_eq!(result, Ok(("", None)));
+/// ```
 pub fn location<I, E>(i: I) -> IResult<I, Option<Location>, E>
 where
     I: Input + Offset + ParseTo<f64> + AsBytes,
@@ -43,24 +195,16 @@ where
     alt((
         value(None, tag(",,,")),
         separated_pair(
-            (with_take(2u8), f64::parse, char(','), one_of("NS")).map(
-                |(deg, min, _, dir): (u8, _, _, _)| {
-                    let mut lat = deg as f64 + (min / 60.0);
-                    if dir == 'S' {
-                        lat = -lat;
-                    }
-                    lat
-                },
+            with_sign(
+                (with_take(2u8), f64::parse).map(|(deg, min): (u8, f64)| deg as f64 + min / 60.0),
+                'N',
+                'S',
             ),
             char(','),
-            (with_take(3u8), f64::parse, char(','), one_of("EW")).map(
-                |(deg, min, _, dir): (u8, _, _, _)| {
-                    let mut lon = deg as f64 + (min / 60.0);
-                    if dir == 'W' {
-                        lon = -lon;
-                    }
-                    lon
-                },
+            with_sign(
+                (with_take(3u8), f64::parse).map(|(deg, min): (u8, f64)| deg as f64 + min / 60.0),
+                'E',
+                'W',
             ),
         )
         .map(|(lat, lon)| {
@@ -73,6 +217,113 @@ where
     .parse(i)
 }
 
+/// Parses a `dd,mm,yyyy` date with a four-digit year, as found in `ZDA` sentences.
+///
+/// Returns [`None`] if all three fields are empty, and an [`Error::InvalidField`] error
+/// if the date does not exist.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::date_full_year};
+///
+/// let result: IResult<_, _> = date_full_year("29,02,2024");
+/// assert_eq!(result, Ok(("", time::Date::from_calendar_date(2024, time::Month::February, 29).ok())));
+///
+/// let result: IResult<_, _> = date_full_year("29,02,2023");
+/// assert!(result.is_err());
+/// ```
+pub fn date_full_year<I, E>(i: I) -> IResult<I, Option<time::Date>, E>
+where
+    I: Input + Offset,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    alt((value(None, tag(",,")), move |start: I| {
+        let (i, (day, month, year)) = (
+            u8::parse,
+            u8::parse_preceded(char(',')),
+            u16::parse_preceded(char(',')),
+        )
+            .parse(start.clone())?;
+
+        let month = month
+            .try_into()
+            .or(Err(invalid_field(start.take(start.offset(&i)))))?;
+
+        let date = time::Date::from_calendar_date(year as i32, month, day)
+            .or(Err(invalid_field(start.take(start.offset(&i)))))?;
+
+        Ok((i, Some(date)))
+    }))
+    .parse(i)
+}
+
+/// Parses a `hh,mm` local zone offset from UTC, as found in `ZDA` sentences.
+///
+/// The sign of the hours applies to the minutes as well, so `-03,30` is three and a half
+/// hours behind UTC. Returns [`None`] if the hours field is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::utc_offset};
+///
+/// let result: IResult<_, _> = utc_offset("-03,30");
+/// assert_eq!(result, Ok(("", time::UtcOffset::from_hms(-3, -30, 0).ok())));
+/// ```
+pub fn utc_offset<I, E>(i: I) -> IResult<I, Option<time::UtcOffset>, E>
+where
+    I: Input + Offset,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    alt((value(None, char(',')), move |start: I| {
+        let (i, (sign, hours, minutes)) =
+            (opt(one_of("+-")), i8::parse, i8::parse_preceded(char(','))).parse(start.clone())?;
+        let (hours, minutes) = match sign {
+            Some('-') => (-hours, -minutes),
+            _ => (hours, minutes),
+        };
+
+        let time = time::UtcOffset::from_hms(hours, minutes, 0)
+            .or(Err(invalid_field(start.take(start.offset(&i)))))?;
+
+        Ok((i, Some(time)))
+    }))
+    .parse(i)
+}
+
+/// Parses a `x.x,a` magnetic variation, as found in `RMC` sentences.
+///
+/// The variation is negative when it is westerly. Returns [`None`] if the value field
+/// is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::magnetic_variation};
+///
+/// let result: IResult<_, _> = magnetic_variation("003.1,W");
+/// assert_eq!(result, Ok(("", Some(-3.1))));
+/// ```
+pub fn magnetic_variation<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    alt((
+        value(None, char(',')),
+        with_sign(f32::parse, 'E', 'W').map(Some),
+    ))
+    .parse(i)
+}
+
 impl<T, I, E, const N: usize> NmeaParse<I, E> for heapless::Vec<T, N>
 where
     T: NmeaParse<I, E>,
@@ -81,7 +332,7 @@ where
     E: ParseError<I>,
 {
     fn parse(i: I) -> IResult<I, Self, E> {
-        let mut elems = Vec::with_capacity(N);
+        let mut elems = heapless::Vec::new();
         let mut i = i;
 
         match T::parse(i.clone()) {
@@ -94,18 +345,20 @@ where
                     )));
                 }
 
-                elems.push(first);
+                // cannot fail, the vector is empty
+                let _ = elems.push(first);
                 i = i1;
             }
             Err(nom::Err::Error(_)) => {
-                return Ok((i, elems.into_iter().collect()));
+                return Ok((i, elems));
             }
             Err(e) => return Err(e),
         }
 
         loop {
             if elems.len() == N {
-                return Ok((i, elems.into_iter().collect()));
+                let (i, ()) = check_capacity::<T, _, _>(i)?;
+                return Ok((i, elems));
             }
 
             let len = i.input_len();
@@ -119,10 +372,11 @@ where
                         )));
                     }
 
-                    elems.push(next);
+                    // cannot fail, the vector is not full
+                    let _ = elems.push(next);
                     i = i1;
                 }
-                Err(nom::Err::Error(_)) => return Ok((i, elems.into_iter().collect())),
+                Err(nom::Err::Error(_)) => return Ok((i, elems)),
                 Err(e) => return Err(e),
             };
         }
@@ -132,10 +386,91 @@ where
     where
         S: Parser<I, Error = Error<I, E>>,
     {
-        use nom::multi::many_m_n;
+        let mut parser = <T>::parse_preceded(separator);
+
+        move |mut i: I| -> IResult<I, Self, E> {
+            let mut elems = heapless::Vec::new();
+
+            while !elems.is_full() {
+                let len = i.input_len();
+                match parser.parse(i.clone()) {
+                    Ok((i1, next)) => {
+                        // infinite loop check: the parser must always consume
+                        if i1.input_len() == len {
+                            return Err(nom::Err::Error(nom::error::make_error(
+                                i,
+                                nom::error::ErrorKind::ManyMN,
+                            )));
+                        }
+
+                        // cannot fail, the vector is not full
+                        let _ = elems.push(next);
+                        i = i1;
+                    }
+                    Err(nom::Err::Error(_)) => return Ok((i, elems)),
+                    Err(e) => return Err(e),
+                }
+            }
+
+            check_capacity::<T, _, _>(i).map(|(i, ())| (i, elems))
+        }
+    }
+}
+
+/// Applies the overflow policy to the input following a full `heapless::Vec`.
+///
+/// By default, elements beyond the capacity are left unparsed. With the `strict-capacity`
+/// feature, another element following the full vector is rejected with
+/// [`ErrorKind::TooLarge`](nom::error::ErrorKind::TooLarge).
+fn check_capacity<T, I, E>(i: I) -> IResult<I, (), E>
+where
+    T: NmeaParse<I, E>,
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    if !cfg!(feature = "strict-capacity") {
+        return Ok((i, ()));
+    }
+
+    if let Ok((rest, _)) = T::parse_preceded(char(',')).parse(i.clone()) {
+        // An element consuming nothing but the separator is an empty trailing field
+        if rest.input_len() + 1 < i.input_len() {
+            return Err(nom::Err::Error(nom::error::make_error(
+                i,
+                nom::error::ErrorKind::TooLarge,
+            )));
+        }
+    }
+
+    Ok((i, ()))
+}
+
+/// Parses a text field up to the next comma into a fixed-capacity string.
+///
+/// The field must not be empty, so that an empty `Option<heapless::String<N>>` field is
+/// [`None`]. A field longer than the capacity is rejected with
+/// [`ErrorKind::TooLarge`](nom::error::ErrorKind::TooLarge) rather than truncated.
+impl<I, E, const N: usize> NmeaParse<I, E> for heapless::String<N>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let (rest, field) = take_till1(|c: <I as Input>::Item| c.as_char() == ',').parse(i)?;
+
+        let mut string = heapless::String::new();
+        for c in field.iter_elements() {
+            if string.push(c.as_char()).is_err() {
+                return Err(nom::Err::Error(nom::error::make_error(
+                    field,
+                    nom::error::ErrorKind::TooLarge,
+                )));
+            }
+        }
 
-        many_m_n(0, N, <T>::parse_preceded(separator))
-            .map(|elems| elems.into_iter().collect::<heapless::Vec<_, N>>())
+        Ok((rest, string))
     }
 }
 
@@ -148,29 +483,110 @@ where
     E: ParseError<I>,
 {
     fn parse(i: I) -> IResult<I, Self, E> {
-        let (i, (hour, minute, second)) = (with_take(2u8), with_take(2u8), f32::parse).parse(i)?;
+        time_with_precision(TimePrecision::Milliseconds).parse(i)
+    }
+}
 
-        if second.is_sign_negative() {
-            return Err(nom::Err::Error(nom::error::make_error(
-                i.clone(),
-                nom::error::ErrorKind::Verify,
-            )));
-        }
+/// Parses a `hhmmss.ss` time, keeping the fractional seconds up to the given precision.
+///
+/// Any number of fractional digits is accepted. Digits beyond the precision are truncated,
+/// not rounded, so that `235959.9999` never rolls over to the next day: with the default
+/// [`TimePrecision::Milliseconds`], both `123456.999` and `123456.9999` give `12:34:56.999`.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult,
+///     nmea_content::{TimePrecision, time_with_precision},
+/// };
+/// use nom::Parser;
+///
+/// let result: IResult<_, _> =
+///     time_with_precision(TimePrecision::Microseconds).parse("123456.1234567");
+/// let (_, time) = result.unwrap();
+/// assert_eq!(time.microsecond(), 123_456);
+/// ```
+pub fn time_with_precision<I, E>(
+    precision: TimePrecision,
+) -> impl Parser<I, Output = time::Time, Error = Error<I, E>>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    let unit = match precision {
+        TimePrecision::Milliseconds => 1_000_000,
+        TimePrecision::Microseconds => 1_000,
+        TimePrecision::Nanoseconds => 1,
+    };
 
-        let milliseconds = second.fract() * 1000.0;
-        let second = second.trunc();
+    move |start: I| -> IResult<I, time::Time, E> {
+        let (i, (hour, minute, second)) =
+            (with_take(2u8), with_take(2u8), with_take(2u8)).parse(start.clone())?;
+        let (i, fraction) = opt(preceded(char('.'), digit0)).parse(i)?;
 
-        let time = time::Time::from_hms_milli(hour, minute, second as u8, milliseconds as u16).or(
-            Err(nom::Err::Error(nom::error::make_error(
-                i.clone(),
-                nom::error::ErrorKind::Verify,
-            ))),
-        )?;
+        let nanosecond = fraction.map_or(0, |fraction| {
+            // Digits beyond nanoseconds are dropped, missing ones are zero
+            fraction
+                .iter_elements()
+                .map(|digit| digit.as_char() as u32 - '0' as u32)
+                .chain(core::iter::repeat(0))
+                .take(9)
+                .fold(0, |nanosecond, digit| nanosecond * 10 + digit)
+        });
+
+        let time = time::Time::from_hms_nano(hour, minute, second, nanosecond - nanosecond % unit)
+            .or(Err(invalid_field(
+                start.take(start.input_len() - i.input_len()),
+            )))?;
 
         Ok((i, time))
     }
 }
 
+impl<I, E> NmeaParse<I, E> for NmeaTime
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(start: I) -> IResult<I, Self, E> {
+        let (i, time) = time_with_precision(TimePrecision::Nanoseconds).parse(start.clone())?;
+
+        // Everything past `hhmmss.` is a fractional digit. Lengths are compared rather than
+        // offsets, as `digit0` misplaces the empty remainder at the end of the input
+        let consumed = start.input_len() - i.input_len();
+        let fractional_digits = consumed.saturating_sub(7).min(u8::MAX as usize) as u8;
+
+        Ok((
+            i,
+            NmeaTime {
+                time,
+                fractional_digits,
+            },
+        ))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::NmeaEncode for NmeaTime {
+    fn encode_to(&self, out: &mut alloc::string::String) {
+        use core::fmt::Write;
+
+        let (hour, minute, second, nanosecond) = self.time.as_hms_nano();
+        let _ = write!(out, "{hour:02}{minute:02}{second:02}");
+
+        if self.fractional_digits > 0 {
+            let digits = self.fractional_digits as usize;
+            let kept = digits.min(9);
+            let fraction = nanosecond / 10u32.pow((9 - kept) as u32);
+            let _ = write!(out, ".{fraction:0kept$}");
+            out.extend(core::iter::repeat_n('0', digits - kept));
+        }
+    }
+}
+
 impl<I, E> NmeaParse<I, E> for time::Date
 where
     I: Input + for<'a> Compare<&'a [u8]>,
@@ -179,24 +595,49 @@ where
     E: ParseError<I>,
 {
     fn parse(i: I) -> IResult<I, Self, E> {
-        let (i, (day, month, year)): (_, (_, u8, _)) =
-            (with_take(2u8), with_take(2u8), with_take(2u8)).parse(i)?;
+        date_with_pivot(83).parse(i)
+    }
+}
 
-        let month = month
-            .try_into()
-            .or(Err(nom::Err::Error(nom::error::make_error(
-                i.clone(),
-                nom::error::ErrorKind::Verify,
-            ))))?;
-
-        let year = match year {
-            83..=99 => year + 1900,
-            _ => year + 2000,
+/// Parses a `ddmmyy` date, mapping two-digit years at or above the pivot to the 1900s.
+///
+/// Years below the pivot are in the 2000s. The [`time::Date`] implementation of
+/// [`NmeaParse`] uses a pivot of `83`, so that `830101` is 1983 and `820101` is 2082.
+/// Replaying older logs may need a lower pivot.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, nmea_content::parsers::date_with_pivot};
+/// use nom::Parser;
+///
+/// let result: IResult<_, _> = date_with_pivot(70).parse("010175");
+/// assert_eq!(result.unwrap().1.year(), 1975);
+///
+/// let result: IResult<_, _> = date_with_pivot(70).parse("010169");
+/// assert_eq!(result.unwrap().1.year(), 2069);
+/// ```
+pub fn date_with_pivot<I, E>(pivot: u8) -> impl Parser<I, Output = time::Date, Error = Error<I, E>>
+where
+    I: Input + for<'a> Compare<&'a [u8]>,
+    I: Compare<&'static str>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    move |start: I| -> IResult<I, time::Date, E> {
+        let (i, (day, month, year)): (_, (_, u8, i32)) =
+            (with_take(2u8), with_take(2u8), with_take(2u8)).parse(start.clone())?;
+
+        let month = month.try_into().or(Err(invalid_field(start.take(6))))?;
+
+        let year = if year >= pivot as i32 {
+            year + 1900
+        } else {
+            year + 2000
         };
 
-        let date = time::Date::from_calendar_date(year, month, day).or(Err(nom::Err::Error(
-            nom::error::make_error(i.clone(), nom::error::ErrorKind::Verify),
-        )))?;
+        let date = time::Date::from_calendar_date(year, month, day)
+            .or(Err(invalid_field(start.take(6))))?;
 
         Ok((i, date))
     }
@@ -204,9 +645,201 @@ where
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{IResult, NmeaParse};
     use nom::{Parser, character::complete::char};
 
+    #[test]
+    fn test_parse_time() {
+        let cases = [
+            ("123456", 0),
+            ("123456.", 0),
+            ("123456.5", 500_000_000),
+            ("123456.78", 780_000_000),
+            ("123456.999", 999_000_000),
+            // Over-precise fractions are truncated, not rounded
+            ("123456.1234", 123_000_000),
+            ("123456.9999", 999_000_000),
+            ("123456.123456789123456789", 123_000_000),
+        ];
+
+        for (input, nanosecond) in cases {
+            let result: IResult<_, _> = time::Time::parse(input);
+            let expected = time::Time::from_hms_nano(12, 34, 56, nanosecond).unwrap();
+            assert_eq!(result, Ok(("", expected)), "{input:?}");
+        }
+
+        // Truncation never rolls over to the next day
+        let result: IResult<_, _> = time::Time::parse("235959.9999");
+        let expected = time::Time::from_hms_milli(23, 59, 59, 999).unwrap();
+        assert_eq!(result, Ok(("", expected)));
+
+        for input in ["123460", "246000", "12345", "1234-1.0"] {
+            let result: IResult<_, time::Time> = time::Time::parse(input);
+            assert!(result.is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_time_precision() {
+        let cases = [
+            (TimePrecision::Milliseconds, 123_000_000),
+            (TimePrecision::Microseconds, 123_456_000),
+            (TimePrecision::Nanoseconds, 123_456_789),
+        ];
+
+        for (precision, nanosecond) in cases {
+            let result: IResult<_, _> = time_with_precision(precision).parse("123456.1234567891");
+            let expected = time::Time::from_hms_nano(12, 34, 56, nanosecond).unwrap();
+            assert_eq!(result, Ok(("", expected)), "{precision:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_nmea_time() {
+        let cases = [
+            ("123456", 0, 0),
+            ("123456.", 0, 0),
+            ("123456.5", 500_000_000, 1),
+            ("123456.50", 500_000_000, 2),
+            ("123456.123", 123_000_000, 3),
+            ("123456.123456789123", 123_456_789, 12),
+        ];
+
+        for (input, nanosecond, fractional_digits) in cases {
+            let result: IResult<_, _> = NmeaTime::parse(input);
+            let expected = NmeaTime {
+                time: time::Time::from_hms_nano(12, 34, 56, nanosecond).unwrap(),
+                fractional_digits,
+            };
+            assert_eq!(result, Ok(("", expected)), "{input:?}");
+        }
+
+        let result: IResult<_, NmeaTime> = NmeaTime::parse("246000.00");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::InvalidField("246000.00")))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_nmea_time_round_trip() {
+        use crate::NmeaEncode;
+
+        for input in [
+            "123456",
+            "123456.5",
+            "123456.50",
+            "000000.000",
+            "235959.999999999",
+        ] {
+            let result: IResult<_, NmeaTime> = NmeaTime::parse(input);
+            let (_, time) = result.unwrap();
+            assert_eq!(time.encode(), input);
+        }
+
+        // Digits beyond nanoseconds are kept as zeros
+        let result: IResult<_, NmeaTime> = NmeaTime::parse("123456.1234567891");
+        assert_eq!(result.unwrap().1.encode(), "123456.1234567890");
+    }
+
+    #[test]
+    fn test_parse_date_pivot() {
+        let cases = [
+            (83, "010183", 1983),
+            (83, "010182", 2082),
+            (83, "311299", 1999),
+            (83, "010100", 2000),
+            (70, "010170", 1970),
+            (70, "010169", 2069),
+            (0, "010100", 1900),
+            (100, "010199", 2099),
+        ];
+
+        for (pivot, input, year) in cases {
+            let result: IResult<_, _> = date_with_pivot(pivot).parse(input);
+            let (_, date) = result.unwrap();
+            assert_eq!(date.year(), year, "{pivot} {input:?}");
+        }
+
+        let result: IResult<_, time::Date> = time::Date::parse("010183");
+        assert_eq!(result.unwrap().1.year(), 1983);
+    }
+
+    #[test]
+    fn test_parse_invalid_field() {
+        let result: IResult<_, time::Time> = time::Time::parse("246000.00,A");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::InvalidField("246000.00")))
+        );
+
+        let result: IResult<_, time::Date> = time::Date::parse("311324,A");
+        assert_eq!(result, Err(nom::Err::Error(Error::InvalidField("311324"))));
+
+        let result: IResult<_, time::Date> = time::Date::parse("310225,A");
+        assert_eq!(result, Err(nom::Err::Error(Error::InvalidField("310225"))));
+    }
+
+    #[test]
+    fn test_with_sign() {
+        let cases = [
+            ("4916.45,N,12311.12,E", 49.274166, 123.185333),
+            ("4916.45,S,12311.12,E", -49.274166, 123.185333),
+            ("4916.45,N,12311.12,W", 49.274166, -123.185333),
+            ("4916.45,S,12311.12,W", -49.274166, -123.185333),
+        ];
+
+        for (input, latitude, longitude) in cases {
+            let result: IResult<_, _> = location(input);
+            let (rest, parsed) = result.unwrap();
+            let parsed = parsed.unwrap();
+            assert_eq!(rest, "", "{input:?}");
+            assert!((parsed.latitude - latitude).abs() < 1e-6, "{input:?}");
+            assert!((parsed.longitude - longitude).abs() < 1e-6, "{input:?}");
+        }
+
+        let result: IResult<_, _> = location("4916.45,E,12311.12,W");
+        assert!(result.is_err());
+        let result: IResult<_, _> = location("4916.45,N,12311.12,S");
+        assert!(result.is_err());
+
+        let result: IResult<_, _> = magnetic_variation("003.1,E");
+        assert_eq!(result, Ok(("", Some(3.1))));
+        let result: IResult<_, _> = magnetic_variation("003.1,W");
+        assert_eq!(result, Ok(("", Some(-3.1))));
+        let result: IResult<_, _> = magnetic_variation("003.1,N");
+        assert!(result.is_err());
+
+        let result: IResult<_, i8> = with_sign(i8::parse, '+', '-').parse("5,-");
+        assert_eq!(result, Ok(("", -5)));
+        let result: IResult<_, i8> = with_sign(i8::parse, '+', '-').parse("5,+");
+        assert_eq!(result, Ok(("", 5)));
+    }
+
+    #[test]
+    fn test_parse_heapless_string() {
+        let result: IResult<_, heapless::String<8>> = heapless::String::parse("WPT 1,A");
+        assert_eq!(result, Ok((",A", "WPT 1".try_into().unwrap())));
+
+        let result: IResult<_, heapless::String<5>> = heapless::String::parse("WPT 1");
+        assert_eq!(result, Ok(("", "WPT 1".try_into().unwrap())));
+
+        // Overflow
+        let result: IResult<_, heapless::String<4>> = heapless::String::parse("WPT 1,A");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("WPT 1", nom::error::ErrorKind::TooLarge)
+            )))
+        );
+
+        let result: IResult<_, _> =
+            Option::<heapless::String<8>>::parse_preceded(char(',')).parse(",,A");
+        assert_eq!(result, Ok((",A", None)));
+    }
+
     #[test]
     fn test_parse_heapless_vec() {
         let input = "1,2,,4";
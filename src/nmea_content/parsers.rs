@@ -0,0 +1,35 @@
+//! # Field Parsers
+//!
+//! This module exposes the field parsers used by the built-in sentences, so that custom
+//! sentences get the same validated location, date and unit handling.
+//!
+//! They can be used with the `parser` attribute of the [`NmeaParse`](crate::NmeaParse)
+//! derive macro:
+//!
+//! ```rust
+//! use nmea0183_parser::{
+//!     IResult, NmeaParse,
+//!     nmea_content::{
+//!         Location,
+//!         parsers::{location, with_unit},
+//!     },
+//! };
+//!
+//! #[derive(NmeaParse)]
+//! struct Waypoint {
+//!     #[nmea(parser(location))]
+//!     location: Option<Location>,
+//!     #[nmea(parser(with_unit('M')))]
+//!     altitude: Option<f32>,
+//! }
+//!
+//! let result: IResult<_, _> = Waypoint::parse("4807.038,N,01131.000,E,545.4,M");
+//! let (_, waypoint) = result.unwrap();
+//! assert!(waypoint.location.is_some());
+//! assert_eq!(waypoint.altitude, Some(545.4));
+//! ```
+
+pub use super::parse::{
+    date_full_year, date_with_pivot, hex, location, magnetic_variation, time_with_precision,
+    utc_offset, with_sign, with_take, with_unit,
+};
@@ -0,0 +1,130 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaParse, nmea_content::parse::hex};
+
+/// ALM - GPS Almanac Data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_alm_gps_almanac_data>
+///
+/// ```text
+///         1 2 3  4    5  6    7  8    9    10     11     12     13     14  15
+///         | | |  |    |  |    |  |    |    |      |      |      |      |   |
+///  $--ALM,x,x,xx,xxxx,hh,hhhh,hh,hhhh,hhhh,hhhhhh,hhhhhh,hhhhhh,hhhhhh,hhh,hhh*hh<CR><LF>
+/// ```
+///
+/// The almanac of a satellite is sent in a message of its own. Fields 5 to 15 are the raw
+/// hexadecimal words of the almanac page, without any scaling applied.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct ALM {
+    /// Total number of messages
+    pub total_messages: u8,
+    /// Message number
+    pub message_number: u8,
+    /// Satellite PRN number (01 to 32)
+    pub sat_prn: u8,
+    /// GPS week number
+    pub week: Option<u16>,
+    #[nmea(parser(hex))]
+    /// SV health, bits 17-24 of each almanac page
+    pub svn_health: Option<u8>,
+    #[nmea(parser(hex))]
+    /// Eccentricity
+    pub eccentricity: Option<u16>,
+    #[nmea(parser(hex))]
+    /// Almanac reference time
+    pub reference_time: Option<u8>,
+    #[nmea(parser(hex))]
+    /// Inclination angle
+    pub inclination: Option<u16>,
+    #[nmea(parser(hex))]
+    /// Rate of right ascension
+    pub right_ascension_rate: Option<u16>,
+    #[nmea(parser(hex))]
+    /// Root of semi-major axis
+    pub sqrt_semi_major_axis: Option<u32>,
+    #[nmea(parser(hex))]
+    /// Argument of perigee
+    pub argument_of_perigee: Option<u32>,
+    #[nmea(parser(hex))]
+    /// Longitude of ascension node
+    pub ascension_node_longitude: Option<u32>,
+    #[nmea(parser(hex))]
+    /// Mean anomaly
+    pub mean_anomaly: Option<u32>,
+    #[nmea(parser(hex))]
+    /// F0 clock parameter
+    pub clock_f0: Option<u16>,
+    #[nmea(parser(hex))]
+    /// F1 clock parameter
+    pub clock_f1: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, IResult};
+
+    #[test]
+    fn test_alm_parsing() {
+        let result: IResult<_, _> =
+            ALM::parse("1,1,15,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                ALM {
+                    total_messages: 1,
+                    message_number: 1,
+                    sat_prn: 15,
+                    week: Some(1159),
+                    svn_health: Some(0x00),
+                    eccentricity: Some(0x441D),
+                    reference_time: Some(0x4E),
+                    inclination: Some(0x16BE),
+                    right_ascension_rate: Some(0xFD5E),
+                    sqrt_semi_major_axis: Some(0xA10C9F),
+                    argument_of_perigee: Some(0x4A2DA4),
+                    ascension_node_longitude: Some(0x686E81),
+                    mean_anomaly: Some(0x58CBE1),
+                    clock_f0: Some(0x0A4),
+                    clock_f1: Some(0x001),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_alm_empty_fields() {
+        let result: IResult<_, _> = ALM::parse("32,1,15,,,,,,,,,,,,");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                ALM {
+                    total_messages: 32,
+                    message_number: 1,
+                    sat_prn: 15,
+                    ..Default::default()
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_alm_invalid_hex() {
+        // The SV health does not fit in a byte
+        let result: IResult<_, ALM> =
+            ALM::parse("1,1,15,1159,100,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001");
+        assert!(matches!(
+            result,
+            Err(nom::Err::Error(Error::InvalidField("100")))
+        ));
+
+        let result: IResult<_, ALM> =
+            ALM::parse("1,1,15,1159,00,441g,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "nmea-v2-3")]
+use crate::nmea_content::FaaMode;
+use crate::{
+    self as nmea0183_parser, NmeaParse,
+    nmea_content::{
+        Location, WaypointId,
+        parse::{location, with_unit},
+    },
+};
+
+/// BWR - Bearing and Distance to Waypoint - Rhumb Line
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_bwr_bearing_and_distance_to_waypoint_rhumb_line>
+///
+/// ```text
+///                                                          12
+///         1         2       3 4        5 6   7 8   9 10  11|
+///         |         |       | |        | |   | |   | |   | |
+///  $--BWR,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c*hh<CR><LF>
+/// ```
+///
+/// NMEA 2.3:
+///
+/// ```text
+///                                                          12   13
+///         1         2       3 4        5 6   7 8   9 10  11|    |
+///         |         |       | |        | |   | |   | |   | |    |
+///  $--BWR,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c,m*hh<CR><LF>
+/// ```
+///
+/// The bearing and distance are computed along the rhumb line, the path of constant
+/// bearing to the waypoint, unlike [`BWC`](crate::nmea_content::BWC) which follows the
+/// great circle.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct BWR {
+    /// Time of the observation in UTC
+    pub fix_time: Option<time::Time>,
+    #[nmea(parser(location))]
+    /// Location (latitude and longitude) of the waypoint
+    pub location: Option<Location>,
+    #[nmea(parser(with_unit('T')))]
+    /// Bearing to the waypoint in degrees true
+    pub bearing_true: Option<f32>,
+    #[nmea(parser(with_unit('M')))]
+    /// Bearing to the waypoint in degrees magnetic
+    pub bearing_magnetic: Option<f32>,
+    #[nmea(parser(with_unit('N')))]
+    /// Distance to the waypoint in nautical miles
+    pub distance: Option<f32>,
+    /// Waypoint identifier
+    pub waypoint_id: WaypointId,
+    #[cfg(feature = "nmea-v2-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
+    /// FAA Mode Indicator
+    pub faa_mode: Option<FaaMode>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_bwr_parsing() {
+        let cases = [",", ",A"];
+
+        for &input in &cases {
+            let i = format!("220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM{input}");
+
+            let result: IResult<_, _> = BWR::parse(i.as_str());
+            let (_, bwr) = result.unwrap();
+            assert_eq!(bwr.fix_time, time::Time::from_hms(22, 5, 16).ok());
+            assert_eq!(bwr.bearing_true, Some(213.8));
+            assert_eq!(bwr.bearing_magnetic, Some(218.0));
+            assert_eq!(bwr.distance, Some(4.6));
+            assert_eq!(bwr.waypoint_id, "EGLM");
+
+            let location = bwr.location.unwrap();
+            assert!((location.latitude - (51.0 + 30.02 / 60.0)).abs() < 1e-9);
+            assert!((location.longitude + (0.0 + 46.34 / 60.0)).abs() < 1e-9);
+        }
+
+        let result: IResult<_, _> = BWR::parse("220516,,,,,,T,,M,,N,EGLM,");
+        let (_, bwr) = result.unwrap();
+        assert_eq!(bwr.location, None);
+        assert_eq!(bwr.distance, None);
+    }
+
+    #[cfg(feature = "nmea-v2-3")]
+    #[test]
+    fn test_bwr_parsing_v2_3() {
+        let cases = ["", "Z", ",Z"];
+
+        for &input in &cases {
+            let i = format!("220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM{input}");
+
+            let result: IResult<_, _> = BWR::parse(i.as_str());
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -1,13 +1,17 @@
-use std::time::Duration;
+use core::{ops::RangeInclusive, time::Duration};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser, combinator::verify, error::ParseError,
+};
+
 use crate::{
-    self as nmea0183_parser, NmeaParse,
+    self as nmea0183_parser, IResult, NmeaParse,
     nmea_content::{
-        Location, Quality,
-        parse::{location, with_unit},
+        FixInfo, Location, Quality,
+        parse::{invalid_field, location, with_unit},
     },
 };
 
@@ -43,17 +47,86 @@ pub struct GGA {
     /// Geoidal separation in meters, the difference between the WGS-84 earth ellipsoid and mean sea level (geoid),
     /// negative values indicate that the geoid is below the ellipsoid
     pub geoidal_separation: Option<f32>,
-    #[nmea(map(|value| value.map(|sec| Duration::from_millis((sec * 1000.0) as u64))), parse_as(Option<f32>))]
+    #[nmea(parser(age_of_dgps))]
     /// Age of Differential GPS data in seconds, time since last SC104 type 1 or 9 update, null field when DGPS is not used
     pub age_of_dgps: Option<Duration>,
     /// Differential reference station ID
     pub ref_station_id: Option<u16>,
 }
 
+impl GGA {
+    /// Plausible altitude range in meters, used by [`GGA::parse_checked`]
+    pub const ALTITUDE_RANGE: RangeInclusive<f32> = -1000.0..=20000.0;
+
+    /// Parses a GGA sentence, rejecting implausible altitudes.
+    ///
+    /// Some receivers emit absurd altitudes during a cold start. Unlike [`GGA::parse`],
+    /// which accepts any altitude, this fails with
+    /// [`ErrorKind::Verify`](nom::error::ErrorKind::Verify) if the altitude is present and
+    /// outside of [`GGA::ALTITUDE_RANGE`]. A missing altitude is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, nmea_content::GGA};
+    ///
+    /// let result: IResult<_, _> =
+    ///     GGA::parse_checked("001043.00,4404.14036,N,12118.85961,W,1,12,0.98,1113.0,M,-21.3,M,,");
+    /// assert!(result.is_ok());
+    ///
+    /// let result: IResult<_, _> =
+    ///     GGA::parse_checked("001043.00,4404.14036,N,12118.85961,W,1,12,0.98,99999.0,M,-21.3,M,,");
+    /// assert!(result.is_err());
+    /// ```
+    pub fn parse_checked<'a, E>(i: &'a str) -> IResult<&'a str, Self, E>
+    where
+        E: ParseError<&'a str>,
+    {
+        verify(GGA::parse, |gga: &GGA| {
+            gga.altitude
+                .is_none_or(|altitude| Self::ALTITUDE_RANGE.contains(&altitude))
+        })
+        .parse(i)
+    }
+}
+
+impl From<&GGA> for FixInfo {
+    fn from(value: &GGA) -> Self {
+        Self {
+            quality: (&value.fix_quality).into(),
+            satellite_count: value.satellite_count,
+            hdop: value.hdop,
+        }
+    }
+}
+
+/// Parses the age of differential GPS data in seconds, with millisecond resolution.
+///
+/// Negative and non-finite ages (e.g. `nan` or `inf`) are rejected rather than silently
+/// saturated to zero or [`u64::MAX`] milliseconds.
+pub(super) fn age_of_dgps<I, E>(i: I) -> IResult<I, Option<Duration>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    let (rest, age) = <Option<f32>>::parse(i.clone())?;
+
+    match age {
+        None => Ok((rest, None)),
+        Some(sec) if sec.is_finite() && sec >= 0.0 => {
+            Ok((rest, Some(Duration::from_millis((sec * 1000.0) as u64))))
+        }
+        Some(_) => Err(invalid_field(i.take(i.offset(&rest)))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::IResult;
+    use crate::{Error, nmea_content::FixQuality};
 
     #[test]
     fn test_gga_parsing() {
@@ -81,4 +154,89 @@ mod tests {
             assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
         }
     }
+
+    #[test]
+    fn test_gga_parse_checked() {
+        let sentence = |altitude: &str| {
+            format!("001043.00,4404.14036,N,12118.85961,W,1,12,0.98,{altitude},M,-21.3,M,,")
+        };
+
+        for altitude in ["-1000.0", "0", "1113.0", "20000.0", ""] {
+            let input = sentence(altitude);
+            let result: IResult<_, _> = GGA::parse_checked(input.as_str());
+            assert!(result.is_ok(), "{altitude:?}: {result:?}");
+        }
+
+        for altitude in ["-1000.1", "20000.1", "99999.0", "nan"] {
+            let input = sentence(altitude);
+            let result: IResult<_, _> = GGA::parse_checked(input.as_str());
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(Error::ParsingError(
+                    nom::error::Error::new(input.as_str(), nom::error::ErrorKind::Verify)
+                ))),
+                "{altitude:?}"
+            );
+
+            // Unchecked parsing accepts any altitude
+            let result: IResult<_, _> = GGA::parse(input.as_str());
+            assert!(result.is_ok(), "{altitude:?}");
+        }
+    }
+
+    #[test]
+    fn test_gga_fix_info() {
+        let result: IResult<_, _> =
+            GGA::parse("001043.00,4404.14036,N,12118.85961,W,2,12,0.98,1113.0,M,-21.3,M,,");
+        let (_, gga) = result.unwrap();
+        assert_eq!(
+            FixInfo::from(&gga),
+            FixInfo {
+                quality: FixQuality::Differential,
+                satellite_count: Some(12),
+                hdop: Some(0.98),
+            }
+        );
+
+        let result: IResult<_, _> = GGA::parse(",,,,,0,,,,M,,M,,");
+        let (_, gga) = result.unwrap();
+        let fix_info = FixInfo::from(&gga);
+        assert_eq!(fix_info.quality, FixQuality::NoFix);
+        assert!(!fix_info.quality.is_gnss_fix());
+        assert_eq!(fix_info.satellite_count, None);
+    }
+
+    #[test]
+    fn test_gga_age_of_dgps() {
+        let cases = [
+            ("", None),
+            ("0", Some(Duration::ZERO)),
+            ("0.25", Some(Duration::from_millis(250))),
+            ("1.5", Some(Duration::from_millis(1500))),
+            ("12", Some(Duration::from_secs(12))),
+        ];
+
+        for (age, expected) in cases {
+            let i = format!(
+                "001043.00,4404.14036,N,12118.85961,W,2,12,0.98,1113.0,M,-21.3,M,{age},0001"
+            );
+
+            let result: IResult<_, _> = GGA::parse(i.as_str());
+            let (_, gga) = result.unwrap();
+            assert_eq!(gga.age_of_dgps, expected, "{age:?}");
+        }
+
+        for age in ["nan", "NaN", "inf", "-1.0", "-0.25"] {
+            let i = format!(
+                "001043.00,4404.14036,N,12118.85961,W,2,12,0.98,1113.0,M,-21.3,M,{age},0001"
+            );
+
+            let result: IResult<_, _> = GGA::parse(i.as_str());
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(Error::InvalidField(age))),
+                "{age:?}"
+            );
+        }
+    }
 }
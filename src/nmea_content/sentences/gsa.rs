@@ -1,10 +1,14 @@
+use nom::{
+    AsChar, Input, Parser, bytes::complete::take_till, character::complete::char,
+    error::ParseError, sequence::preceded,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "nmea-v4-11")]
 use crate::nmea_content::SystemId;
 use crate::{
-    self as nmea0183_parser, NmeaParse,
+    self as nmea0183_parser, IResult, NmeaParse,
     nmea_content::{FixMode, SelectionMode},
 };
 
@@ -32,7 +36,7 @@ pub struct GSA {
     pub selection_mode: SelectionMode,
     /// Fix mode
     pub fix_mode: FixMode,
-    #[nmea(map(|sats| sats.into_iter().flatten().collect()), parse_as([Option<u8>; 12]))]
+    #[nmea(parser(fix_sats_prn))]
     /// PRN numbers of the satellites used in the fix, up to 12
     pub fix_sats_prn: heapless::Vec<u8, 12>,
     /// Position Dilution of Precision
@@ -47,6 +51,47 @@ pub struct GSA {
     pub system_id: Option<SystemId>,
 }
 
+/// Number of fields following the PRN numbers: PDOP, HDOP, VDOP and, since NMEA 4.11,
+/// the system ID
+const TRAILING_FIELDS: usize = 4;
+
+/// Parses the 12 PRN number fields, applying the overflow policy to any extra PRN.
+///
+/// Extra PRNs can only be told apart from the DOP fields by counting the remaining fields,
+/// so a 13th PRN in a sentence without the NMEA 4.11 system ID is indistinguishable from
+/// a 12 PRN sentence with one and is not detected.
+///
+/// By default, extra PRNs are skipped. With the `strict-capacity` feature, they are
+/// rejected with [`ErrorKind::TooLarge`](nom::error::ErrorKind::TooLarge).
+fn fix_sats_prn<I, E>(i: I) -> IResult<I, heapless::Vec<u8, 12>, E>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    let (mut i, sats) = <[Option<u8>; 12]>::parse(i)?;
+
+    let fields = i.iter_elements().filter(|c| c.as_char() == ',').count();
+    let extra = fields.saturating_sub(TRAILING_FIELDS);
+
+    if cfg!(feature = "strict-capacity") && extra > 0 {
+        return Err(nom::Err::Error(nom::error::make_error(
+            i,
+            nom::error::ErrorKind::TooLarge,
+        )));
+    }
+
+    for _ in 0..extra {
+        (i, _) = preceded(
+            char(','),
+            take_till(|c: <I as Input>::Item| c.as_char() == ','),
+        )
+        .parse(i)?;
+    }
+
+    Ok((i, sats.into_iter().flatten().collect()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +117,38 @@ mod tests {
             assert_eq!(result, Ok((",", expected)));
         }
     }
+
+    #[test]
+    fn test_gsa_overflow() {
+        let input = "A,3,1,2,3,4,5,6,7,8,9,10,11,12,13,1.0,2.0,3.0,1";
+        let result: IResult<_, _> = GSA::parse(input);
+
+        if cfg!(feature = "strict-capacity") {
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(nom::error::make_error(
+                    ",13,1.0,2.0,3.0,1",
+                    nom::error::ErrorKind::TooLarge
+                )))
+            );
+        } else {
+            let expected = GSA {
+                selection_mode: SelectionMode::Automatic,
+                fix_mode: FixMode::Fix3D,
+                fix_sats_prn: heapless::Vec::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+                    .unwrap(),
+                pdop: Some(1.0),
+                hdop: Some(2.0),
+                vdop: Some(3.0),
+                #[cfg(feature = "nmea-v4-11")]
+                system_id: Some(SystemId::Gps),
+            };
+
+            if cfg!(feature = "nmea-v4-11") {
+                assert_eq!(result, Ok(("", expected)));
+            } else {
+                assert_eq!(result, Ok((",1", expected)));
+            }
+        }
+    }
 }
@@ -1,11 +1,12 @@
 #[cfg(feature = "nmea-v4-11")]
 use nom::{Input, combinator::opt, number::complete::hex_u32};
+use nom::{Parser, combinator::verify, error::ParseError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "nmea-v4-11")]
-use crate::nmea_content::SignalId;
-use crate::{self as nmea0183_parser, NmeaParse, nmea_content::Satellite};
+use crate::nmea_content::{SignalId, SystemId};
+use crate::{self as nmea0183_parser, IResult, NmeaParse, nmea_content::Satellite};
 
 /// GSV - Satellites in View
 ///
@@ -34,8 +35,67 @@ pub struct GSV {
     #[nmea(cond(!satellites.is_empty() || nmea_input.input_len() > 0))]
     #[nmea(map(|id| id.map(|hex| hex as u8)))]
     #[nmea(parser(opt(hex_u32)))]
-    /// Signal ID of the GNSS system used for the fix
-    pub signal_id: Option<SignalId>,
+    /// Raw signal ID of the GNSS system used for the fix, see [`GSV::signal`]
+    pub signal_id: Option<u8>,
+}
+
+impl GSV {
+    /// Parses a GSV sentence, rejecting satellites with out-of-range elevations or azimuths.
+    ///
+    /// Unlike [`GSV::parse`], this fails with
+    /// [`ErrorKind::Verify`](nom::error::ErrorKind::Verify) if any of the satellites is not
+    /// [in range](Satellite::is_in_range), see [`Satellite::parse_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, nmea_content::GSV};
+    ///
+    /// let result: IResult<_, _> = GSV::parse_checked("1,1,02,05,45,120,38,07,10,010,20,");
+    /// assert!(result.is_ok());
+    ///
+    /// let result: IResult<_, _> = GSV::parse_checked("1,1,02,05,45,120,38,07,10,400,20,");
+    /// assert!(result.is_err());
+    /// ```
+    pub fn parse_checked<'a, E>(i: &'a str) -> IResult<&'a str, Self, E>
+    where
+        E: ParseError<&'a str>,
+    {
+        verify(GSV::parse, |gsv: &GSV| {
+            gsv.satellites.iter().all(Satellite::is_in_range)
+        })
+        .parse(i)
+    }
+}
+
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl GSV {
+    /// Resolves the signal ID of this sentence within the given GNSS system.
+    ///
+    /// GSV sentences carry no system ID of their own, the system is usually taken
+    /// from the talker ID, e.g. with `SystemId::try_from(talker)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{
+    ///     IResult, NmeaParse,
+    ///     nmea_content::{GSV, GalileoSignalId, SignalId, SystemId, TalkerId},
+    /// };
+    ///
+    /// let result: IResult<_, _> = GSV::parse("1,1,00,1");
+    /// let (_, gsv) = result.unwrap();
+    ///
+    /// let system = SystemId::try_from(TalkerId::Galileo).ok();
+    /// assert_eq!(
+    ///     gsv.signal(system),
+    ///     Some(SignalId::Galileo(GalileoSignalId::E5a))
+    /// );
+    /// ```
+    pub fn signal(&self, system: Option<SystemId>) -> Option<SignalId> {
+        self.signal_id.map(|raw| SignalId::from_raw(system, raw))
+    }
 }
 
 #[cfg(test)]
@@ -92,4 +152,59 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_gsv_parse_checked() {
+        let sentence = |satellite: &str| format!("1,1,02,05,45,120,38,{satellite},");
+
+        for satellite in ["07,0,0,20", "07,90,359,20", "07,,,20"] {
+            let input = sentence(satellite);
+            let result: IResult<_, _> = GSV::parse_checked(input.as_str());
+            assert!(result.is_ok(), "{satellite:?}: {result:?}");
+        }
+
+        for satellite in [
+            "07,91,010,20",
+            "07,99,010,20",
+            "07,10,360,20",
+            "07,10,400,20",
+        ] {
+            let input = sentence(satellite);
+
+            let result: IResult<_, _> = GSV::parse(input.as_str());
+            assert!(result.is_ok(), "{satellite:?}: {result:?}");
+
+            let result: IResult<_, GSV> = GSV::parse_checked(input.as_str());
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(crate::Error::ParsingError(
+                    nom::error::Error::new(input.as_str(), nom::error::ErrorKind::Verify)
+                ))),
+                "{satellite:?}"
+            );
+
+            let result: IResult<_, Satellite> = Satellite::parse_checked(satellite);
+            assert!(result.is_err(), "{satellite:?}");
+        }
+    }
+
+    #[test]
+    fn test_gsv_overflow() {
+        let input = "1,1,05,01,60,150,45,02,30,090,30,03,70,270,50,04,10,010,20,05,45,120,38";
+        let result: IResult<_, GSV> = GSV::parse(input);
+
+        if cfg!(feature = "strict-capacity") {
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(nom::error::make_error(
+                    ",05,45,120,38",
+                    nom::error::ErrorKind::TooLarge
+                )))
+            );
+        } else if !cfg!(feature = "nmea-v4-11") {
+            let (rest, gsv) = result.unwrap();
+            assert_eq!(rest, ",05,45,120,38");
+            assert_eq!(gsv.satellites.len(), 4);
+        }
+    }
 }
@@ -1,28 +1,62 @@
+mod alm;
+mod bwc;
+mod bwr;
 mod dbt;
 mod dpt;
 mod gga;
 mod gll;
 mod gsa;
 mod gsv;
+#[cfg(feature = "garmin")]
+mod pgrme;
+mod proprietary;
+#[cfg(feature = "ublox")]
+mod pubx00;
 mod rmc;
+mod rte;
+mod vbw;
 mod vtg;
+mod wpl;
 mod zda;
 
+pub use alm::ALM;
+pub use bwc::BWC;
+pub use bwr::BWR;
 pub use dbt::DBT;
 pub use dpt::DPT;
 pub use gga::GGA;
 pub use gll::GLL;
 pub use gsa::GSA;
 pub use gsv::GSV;
+#[cfg(feature = "garmin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "garmin")))]
+pub use pgrme::PGRME;
+pub use proprietary::Proprietary;
+#[cfg(feature = "ublox")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ublox")))]
+pub use pubx00::PUBX00;
 pub use rmc::RMC;
+pub use rte::RTE;
+pub use vbw::VBW;
 pub use vtg::VTG;
+pub use wpl::WPL;
 pub use zda::ZDA;
 
-use nom::{bytes::complete::take, character::complete::one_of};
+use core::ops::RangeInclusive;
+
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::{tag, take},
+    character::complete::{char, one_of},
+    combinator::{peek, verify},
+    error::ParseError,
+    sequence::{preceded, separated_pair, terminated},
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{self as nmea0183_parser, Error, NmeaParse};
+use crate::{self as nmea0183_parser, Error, IResult, NmeaParse, nmea_content::parse::location};
 
 /// A unified enum representing all supported NMEA 0183 sentence types.
 ///
@@ -121,6 +155,9 @@ use crate::{self as nmea0183_parser, Error, NmeaParse};
 /// | VTG     | Track made good and Ground speed                        | Velocity information             |
 /// | ZDA     | Time & Date - UTC, day, month, year and local time zone | UTC time and date with time zone |
 ///
+/// Proprietary sentences, starting with `P` and a manufacturer code instead of a talker ID,
+/// are parsed into the [`NmeaSentence::Proprietary`] variant with their raw content.
+///
 /// ## NMEA Version Support
 ///
 /// Different NMEA versions may include additional fields in certain sentence types. You can choose the version that matches your equipment by enabling the appropriate feature flags.
@@ -153,12 +190,19 @@ use crate::{self as nmea0183_parser, Error, NmeaParse};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, NmeaParse)]
 #[nmea(pre_exec(let msg = nmea_input;))]
-// TODO: Handle talker ID
-#[nmea(skip_before(2))]
-#[nmea(selector(take(3u8)))]
+#[nmea(selector(preceded(skip_talker_id, alt((tag("P"), take(3u8))))))]
 #[nmea(selection_error(Error::UnrecognizedMessage(msg)))]
 #[nmea(exact)]
 pub enum NmeaSentence {
+    #[nmea(selector("ALM"))]
+    /// GPS Almanac Data
+    ALM(ALM),
+    #[nmea(selector("BWC"))]
+    /// Bearing & Distance to Waypoint - Great Circle
+    BWC(BWC),
+    #[nmea(selector("BWR"))]
+    /// Bearing and Distance to Waypoint - Rhumb Line
+    BWR(BWR),
     #[nmea(selector("DBT"))]
     /// Depth Below Transducer
     DBT(DBT),
@@ -180,32 +224,479 @@ pub enum NmeaSentence {
     #[nmea(selector("RMC"))]
     /// Recommended Minimum Navigation Information
     RMC(RMC),
+    #[nmea(selector("RTE"))]
+    /// Routes
+    RTE(RTE),
+    #[nmea(selector("VBW"))]
+    /// Dual Ground/Water Speed
+    VBW(VBW),
     #[nmea(selector("VTG"))]
     /// Track made good and Ground speed
     VTG(VTG),
+    #[nmea(selector("WPL"))]
+    /// Waypoint Location
+    WPL(WPL),
     #[nmea(selector("ZDA"))]
     /// Time & Date - UTC, day, month, year and local time zone
     ZDA(ZDA),
+    #[nmea(selector("P"), separator(nom::combinator::success(())))]
+    /// Proprietary sentence
+    Proprietary(Proprietary),
+}
+
+impl NmeaSentence {
+    /// Returns the type of the sentence
+    pub fn sentence_type(&self) -> SentenceType {
+        match self {
+            NmeaSentence::ALM(_) => SentenceType::ALM,
+            NmeaSentence::BWC(_) => SentenceType::BWC,
+            NmeaSentence::BWR(_) => SentenceType::BWR,
+            NmeaSentence::DBT(_) => SentenceType::DBT,
+            NmeaSentence::DPT(_) => SentenceType::DPT,
+            NmeaSentence::GGA(_) => SentenceType::GGA,
+            NmeaSentence::GLL(_) => SentenceType::GLL,
+            NmeaSentence::GSA(_) => SentenceType::GSA,
+            NmeaSentence::GSV(_) => SentenceType::GSV,
+            NmeaSentence::RMC(_) => SentenceType::RMC,
+            NmeaSentence::RTE(_) => SentenceType::RTE,
+            NmeaSentence::VBW(_) => SentenceType::VBW,
+            NmeaSentence::VTG(_) => SentenceType::VTG,
+            NmeaSentence::WPL(_) => SentenceType::WPL,
+            NmeaSentence::ZDA(_) => SentenceType::ZDA,
+            NmeaSentence::Proprietary(_) => SentenceType::Proprietary,
+        }
+    }
+
+    /// Parses the sentence content like [`NmeaSentence::parse`], also returning its talker ID.
+    ///
+    /// [`NmeaSentence::parse`] discards the two-character talker ID, which is needed to tell
+    /// apart the sentences of each constellation on multi-GNSS receivers, e.g. `GPGSV` from
+    /// `GLGSV`. Proprietary sentences have no talker ID, so theirs is [`TalkerId::Other`]
+    /// with the `P` and the first character of the manufacturer code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{
+    ///     IResult, Nmea0183ParserBuilder,
+    ///     nmea_content::{NmeaSentence, TalkerId},
+    /// };
+    /// use nom::Parser;
+    ///
+    /// let mut parser = Nmea0183ParserBuilder::new().build(NmeaSentence::parse_with_talker);
+    ///
+    /// let result: IResult<_, _> = parser.parse(
+    ///     "$GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*59\r\n",
+    /// );
+    /// let (_, (talker, sentence)) = result.unwrap();
+    /// assert_eq!(talker, TalkerId::Gnss);
+    /// assert!(matches!(sentence, NmeaSentence::GGA(_)));
+    /// ```
+    pub fn parse_with_talker<'a, E>(i: &'a str) -> IResult<&'a str, (TalkerId, Self), E>
+    where
+        E: ParseError<&'a str>,
+    {
+        (peek(TalkerId::parse), NmeaSentence::parse).parse(i)
+    }
+
+    /// Returns a content parser that only fully parses sentences of the given types.
+    ///
+    /// Sentences of any other type, including unrecognized ones, are skipped after reading
+    /// their type and returned as [`None`], without parsing or validating their fields.
+    /// On high-rate receivers this avoids the cost of parsing unwanted sentences, such as
+    /// the many `GSV` sentences emitted every second by multi-constellation receivers.
+    ///
+    /// The returned parser can be used as the content parser of the
+    /// [`Nmea0183ParserBuilder`](crate::Nmea0183ParserBuilder), in which case the framing
+    /// (including the checksum) is still validated for every sentence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{
+    ///     IResult, Nmea0183ParserBuilder,
+    ///     nmea_content::{NmeaSentence, SentenceType},
+    /// };
+    /// use nom::Parser;
+    ///
+    /// let mut parser =
+    ///     Nmea0183ParserBuilder::new().build(NmeaSentence::parse_filtered(&[SentenceType::ZDA]));
+    ///
+    /// let result: IResult<_, _> = parser.parse("$GPZDA,123456.78,29,02,2024,03,00*60\r\n");
+    /// assert!(matches!(result, Ok((_, Some(NmeaSentence::ZDA(_))))));
+    ///
+    /// let result: IResult<_, _> = parser.parse("$GPGSV,3,2,12,01,40,083,45*44\r\n");
+    /// assert!(matches!(result, Ok((_, None))));
+    /// ```
+    pub fn parse_filtered<'a, E>(
+        types: &[SentenceType],
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, Option<Self>, E>
+    where
+        E: ParseError<&'a str>,
+    {
+        filtered(types, NmeaSentence::parse)
+    }
+
+    /// Parses the sentence content like [`NmeaSentence::parse`], returning any trailing
+    /// fields left unparsed instead of failing.
+    ///
+    /// [`NmeaSentence::parse`] requires the whole content to be consumed, so a sentence
+    /// from newer equipment fails to parse when it carries fields added by a later NMEA
+    /// version than the one enabled with the feature flags, e.g. the system ID of a NMEA
+    /// 4.11 `GSA` sentence without the `nmea-v4-11` feature. This parser ignores such
+    /// fields and returns them as the remaining input, so that parsing is forward
+    /// compatible.
+    ///
+    /// The trade-off is that a sentence with unexpected trailing content, e.g. a corrupted
+    /// one or one from non-compliant equipment, is accepted as well. Only trailing fields
+    /// are tolerated, the fields known to the enabled NMEA version must still be valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, NmeaParse, nmea_content::NmeaSentence};
+    ///
+    /// let content = "GPZDA,123456.78,29,02,2024,03,00,extra";
+    ///
+    /// let result: IResult<_, NmeaSentence> = NmeaSentence::parse(content);
+    /// assert!(result.is_err());
+    ///
+    /// let result: IResult<_, _> = NmeaSentence::parse_lenient(content);
+    /// let (rest, sentence) = result.unwrap();
+    /// assert_eq!(rest, ",extra");
+    /// assert!(matches!(sentence, NmeaSentence::ZDA(_)));
+    /// ```
+    pub fn parse_lenient<'a, E>(i: &'a str) -> IResult<&'a str, Self, E>
+    where
+        E: ParseError<&'a str>,
+    {
+        let msg = i;
+        let header: IResult<_, _, E> = preceded(skip_talker_id, SentenceType::parse).parse(i);
+        let Ok((i, sentence_type)) = header else {
+            return Err(nom::Err::Error(Error::UnrecognizedMessage(msg)));
+        };
+
+        let fields = |i| char(',').parse(i).map(|(i, _)| i);
+        match sentence_type {
+            SentenceType::ALM => ALM::parse.map(NmeaSentence::ALM).parse(fields(i)?),
+            SentenceType::BWC => BWC::parse.map(NmeaSentence::BWC).parse(fields(i)?),
+            SentenceType::BWR => BWR::parse.map(NmeaSentence::BWR).parse(fields(i)?),
+            SentenceType::DBT => DBT::parse.map(NmeaSentence::DBT).parse(fields(i)?),
+            SentenceType::DPT => DPT::parse.map(NmeaSentence::DPT).parse(fields(i)?),
+            SentenceType::GGA => GGA::parse.map(NmeaSentence::GGA).parse(fields(i)?),
+            SentenceType::GLL => GLL::parse.map(NmeaSentence::GLL).parse(fields(i)?),
+            SentenceType::GSA => GSA::parse.map(NmeaSentence::GSA).parse(fields(i)?),
+            SentenceType::GSV => GSV::parse.map(NmeaSentence::GSV).parse(fields(i)?),
+            SentenceType::RMC => RMC::parse.map(NmeaSentence::RMC).parse(fields(i)?),
+            SentenceType::RTE => RTE::parse.map(NmeaSentence::RTE).parse(fields(i)?),
+            SentenceType::VBW => VBW::parse.map(NmeaSentence::VBW).parse(fields(i)?),
+            SentenceType::VTG => VTG::parse.map(NmeaSentence::VTG).parse(fields(i)?),
+            SentenceType::WPL => WPL::parse.map(NmeaSentence::WPL).parse(fields(i)?),
+            SentenceType::ZDA => ZDA::parse.map(NmeaSentence::ZDA).parse(fields(i)?),
+            // Proprietary sentences keep their whole content, so nothing is left unparsed
+            SentenceType::Proprietary => Proprietary::parse.map(NmeaSentence::Proprietary).parse(i),
+        }
+    }
+}
+
+/// Extracts the position and fix time from a `GGA`, `GLL` or `RMC` sentence content.
+///
+/// Only the time and location fields are parsed, the remaining fields are neither
+/// parsed nor validated. This is a fast path for applications that only need the
+/// position, compared to a full [`NmeaSentence::parse`].
+///
+/// Returns [`None`] if the sentence is of any other type, if the time or location
+/// fields are malformed, or if either of them is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::nmea_content::parse_position;
+///
+/// let (location, time) =
+///     parse_position("GPGLL,4916.45,N,12311.12,W,225444,A").unwrap();
+/// assert!((location.latitude - 49.274166).abs() < 1e-6);
+/// assert_eq!(time, time::Time::from_hms(22, 54, 44).unwrap());
+///
+/// assert!(parse_position("GPZDA,123456.78,29,02,2024,03,00").is_none());
+/// ```
+pub fn parse_position(content: &str) -> Option<(Location, time::Time)> {
+    let header: IResult<_, _> =
+        terminated(preceded(skip_talker_id, SentenceType::parse), char(',')).parse(content);
+    let (i, sentence_type) = header.ok()?;
+
+    let position: IResult<_, _> = match sentence_type {
+        SentenceType::GGA => separated_pair(Option::<time::Time>::parse, char(','), location)
+            .map(|(time, location)| (location, time))
+            .parse(i),
+        SentenceType::GLL => separated_pair(location, char(','), Option::parse).parse(i),
+        SentenceType::RMC => (
+            Option::<time::Time>::parse,
+            char(','),
+            Status::parse,
+            char(','),
+            location,
+        )
+            .map(|(time, _, _, _, location)| (location, time))
+            .parse(i),
+        _ => return None,
+    };
+
+    match position.ok()? {
+        (_, (Some(location), Some(time))) => Some((location, time)),
+        _ => None,
+    }
+}
+
+/// Runs the given sentence parser only on sentences of the given types, skipping the others
+/// after reading their type.
+fn filtered<'a, P, E>(
+    types: &[SentenceType],
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Option<NmeaSentence>, E>
+where
+    P: Parser<&'a str, Output = NmeaSentence, Error = Error<&'a str, E>>,
+    E: ParseError<&'a str>,
+{
+    move |i: &'a str| match peek_sentence_type::<E>(i) {
+        Ok((_, sentence_type)) if types.contains(&sentence_type) => parser
+            .parse(i)
+            .map(|(rest, sentence)| (rest, Some(sentence))),
+        _ => Ok((&i[i.len()..], None)),
+    }
+}
+
+/// Recognizes the type of a sentence from its content, without consuming it.
+fn peek_sentence_type<'a, E>(i: &'a str) -> IResult<&'a str, SentenceType, E>
+where
+    E: ParseError<&'a str>,
+{
+    peek(preceded(skip_talker_id, SentenceType::parse)).parse(i)
+}
+
+/// Skips the talker ID of a sentence, which proprietary sentences do not have.
+fn skip_talker_id<'a, E>(i: &'a str) -> IResult<&'a str, &'a str, E>
+where
+    E: ParseError<&'a str>,
+{
+    alt((peek(tag("P")), take(2u8))).parse(i)
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
-#[nmea(selector(one_of("AV")))]
-/// Status Mode Indicator
-pub enum Status {
-    #[nmea(selector('A'))]
-    /// A - Valid
-    Valid,
-    #[default]
-    #[nmea(selector('V'))]
-    /// V - Invalid
-    Invalid,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
+#[nmea(selector(alt((tag("P"), take(3u8)))))]
+/// Type of an NMEA 0183 sentence supported by [`NmeaSentence`]
+pub enum SentenceType {
+    #[nmea(selector("ALM"))]
+    /// GPS Almanac Data
+    ALM,
+    #[nmea(selector("BWC"))]
+    /// Bearing & Distance to Waypoint - Great Circle
+    BWC,
+    #[nmea(selector("BWR"))]
+    /// Bearing and Distance to Waypoint - Rhumb Line
+    BWR,
+    #[nmea(selector("DBT"))]
+    /// Depth Below Transducer
+    DBT,
+    #[nmea(selector("DPT"))]
+    /// Depth of Water
+    DPT,
+    #[nmea(selector("GGA"))]
+    /// Global Positioning System Fix Data
+    GGA,
+    #[nmea(selector("GLL"))]
+    /// Geographic Position - Latitude/Longitude
+    GLL,
+    #[nmea(selector("GSA"))]
+    /// GPS DOP and active satellites
+    GSA,
+    #[nmea(selector("GSV"))]
+    /// Satellites in View
+    GSV,
+    #[nmea(selector("RMC"))]
+    /// Recommended Minimum Navigation Information
+    RMC,
+    #[nmea(selector("RTE"))]
+    /// Routes
+    RTE,
+    #[nmea(selector("VBW"))]
+    /// Dual Ground/Water Speed
+    VBW,
+    #[nmea(selector("VTG"))]
+    /// Track made good and Ground speed
+    VTG,
+    #[nmea(selector("WPL"))]
+    /// Waypoint Location
+    WPL,
+    #[nmea(selector("ZDA"))]
+    /// Time & Date - UTC, day, month, year and local time zone
+    ZDA,
+    #[nmea(selector("P"))]
+    /// Proprietary sentence
+    Proprietary,
+}
+
+impl SentenceType {
+    /// Returns all the sentence types supported by [`NmeaSentence`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::SentenceType;
+    ///
+    /// assert!(SentenceType::all().contains(&SentenceType::GGA));
+    /// ```
+    pub fn all() -> &'static [SentenceType] {
+        &[
+            SentenceType::ALM,
+            SentenceType::BWC,
+            SentenceType::BWR,
+            SentenceType::DBT,
+            SentenceType::DPT,
+            SentenceType::GGA,
+            SentenceType::GLL,
+            SentenceType::GSA,
+            SentenceType::GSV,
+            SentenceType::RMC,
+            SentenceType::RTE,
+            SentenceType::VBW,
+            SentenceType::VTG,
+            SentenceType::WPL,
+            SentenceType::ZDA,
+            SentenceType::Proprietary,
+        ]
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Talker ID of an NMEA 0183 sentence, identifying the kind of device that sent it
+///
+/// Any two alphanumeric characters are accepted, unknown talker IDs are kept as
+/// [`TalkerId::Other`].
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_talker_ids>
+pub enum TalkerId {
+    /// GP - GPS
+    Gps,
+    /// GL - GLONASS
+    Glonass,
+    /// GA - Galileo
+    Galileo,
+    /// GB - BeiDou
+    Beidou,
+    /// GQ - QZSS
+    Qzss,
+    /// GI - NavIC
+    Navic,
+    /// GN - Combined GNSS
+    Gnss,
+    /// HC - Magnetic compass
+    Compass,
+    /// HE - North seeking gyro
+    Gyro,
+    /// II - Integrated instrumentation
+    IntegratedInstrumentation,
+    /// IN - Integrated navigation
+    IntegratedNavigation,
+    /// SD - Depth sounder
+    DepthSounder,
+    /// Any other talker ID
+    Other([u8; 2]),
+}
+
+/// Converts two talker ID characters into a talker ID, e.g. `b"GP"` into [`TalkerId::Gps`].
+impl From<[u8; 2]> for TalkerId {
+    fn from(value: [u8; 2]) -> Self {
+        match &value {
+            b"GP" => TalkerId::Gps,
+            b"GL" => TalkerId::Glonass,
+            b"GA" => TalkerId::Galileo,
+            b"GB" => TalkerId::Beidou,
+            b"GQ" => TalkerId::Qzss,
+            b"GI" => TalkerId::Navic,
+            b"GN" => TalkerId::Gnss,
+            b"HC" => TalkerId::Compass,
+            b"HE" => TalkerId::Gyro,
+            b"II" => TalkerId::IntegratedInstrumentation,
+            b"IN" => TalkerId::IntegratedNavigation,
+            b"SD" => TalkerId::DepthSounder,
+            _ => TalkerId::Other(value),
+        }
+    }
+}
+
+/// Converts a talker ID into its two characters, e.g. for re-encoding.
+impl From<TalkerId> for [u8; 2] {
+    fn from(value: TalkerId) -> Self {
+        match value {
+            TalkerId::Gps => *b"GP",
+            TalkerId::Glonass => *b"GL",
+            TalkerId::Galileo => *b"GA",
+            TalkerId::Beidou => *b"GB",
+            TalkerId::Qzss => *b"GQ",
+            TalkerId::Navic => *b"GI",
+            TalkerId::Gnss => *b"GN",
+            TalkerId::Compass => *b"HC",
+            TalkerId::Gyro => *b"HE",
+            TalkerId::IntegratedInstrumentation => *b"II",
+            TalkerId::IntegratedNavigation => *b"IN",
+            TalkerId::DepthSounder => *b"SD",
+            TalkerId::Other(id) => id,
+        }
+    }
+}
+
+impl<'a, E> NmeaParse<&'a str, E> for TalkerId
+where
+    E: ParseError<&'a str>,
+{
+    fn parse(i: &'a str) -> IResult<&'a str, Self, E> {
+        let (i, id) = verify(take(2u8), |id: &str| {
+            id.bytes().all(|b| b.is_ascii_alphanumeric())
+        })
+        .parse(i)?;
+        let id = id.as_bytes();
+
+        Ok((i, TalkerId::from([id[0], id[1]])))
+    }
+}
+
+crate::one_of_enum! {
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    /// Status Mode Indicator
+    pub enum Status {
+        /// A - Valid
+        'A' => Valid,
+        #[default]
+        /// V - Invalid
+        'V' => Invalid,
+    }
+}
+
+/// Waypoint identifier used in [`WPL`], [`RTE`], [`BWC`] and [`BWR`] sentences, of at most
+/// 10 characters
+pub type WaypointId = heapless::String<10>;
+
+crate::one_of_enum! {
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    /// Route Mode of [`RTE`] sentences
+    pub enum RouteMode {
+        #[default]
+        /// c - Complete route, all the waypoints of the route are listed
+        'c' => Complete,
+        /// w - Working route, the first listed waypoint is the start of the current leg
+        'w' => Working,
+    }
 }
 
 #[cfg(feature = "nmea-v2-3")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
 #[cfg_attr(not(feature = "nmea-v4-11"), nmea(selector(one_of("ACDEFMNRSU"))))]
 #[cfg_attr(feature = "nmea-v4-11", nmea(selector(one_of("ACDEFMNPRSU"))))]
 /// FAA Mode Indicator
@@ -250,10 +741,32 @@ pub enum FaaMode {
     Unsafe,
 }
 
+/// Converts an FAA mode indicator into its selector character, e.g. for re-encoding.
+#[cfg(feature = "nmea-v2-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
+impl From<FaaMode> for char {
+    fn from(value: FaaMode) -> Self {
+        match value {
+            FaaMode::Autonomous => 'A',
+            FaaMode::Caution => 'C',
+            FaaMode::Differential => 'D',
+            FaaMode::Estimated => 'E',
+            FaaMode::FloatRtk => 'F',
+            FaaMode::Manual => 'M',
+            FaaMode::DataNotValid => 'N',
+            #[cfg(feature = "nmea-v4-11")]
+            FaaMode::Precise => 'P',
+            FaaMode::FixedRtk => 'R',
+            FaaMode::Simulator => 'S',
+            FaaMode::Unsafe => 'U',
+        }
+    }
+}
+
 #[cfg(feature = "nmea-v4-11")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
 #[nmea(selector(one_of("ADEMNSV")))]
 /// Navigation Status
 pub enum NavStatus {
@@ -281,8 +794,133 @@ pub enum NavStatus {
     Valid,
 }
 
+/// Converts a navigation status into its selector character, e.g. for re-encoding.
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl From<NavStatus> for char {
+    fn from(value: NavStatus) -> Self {
+        match value {
+            NavStatus::Autonomous => 'A',
+            NavStatus::Differential => 'D',
+            NavStatus::Estimated => 'E',
+            NavStatus::Manual => 'M',
+            NavStatus::NotValid => 'N',
+            NavStatus::Simulator => 'S',
+            NavStatus::Valid => 'V',
+        }
+    }
+}
+
+/// Converts an FAA mode indicator into a status.
+///
+/// Following the NMEA 2.3 convention for the status fields of `RMC` and `GLL`,
+/// only an actual position fix is considered valid:
+///
+/// | FAA mode                                       | Status    |
+/// |------------------------------------------------|-----------|
+/// | `A`, `D`, `F`, `R`, `P`                        | `Valid`   |
+/// | `E`, `M`, `N`, `S`                             | `Invalid` |
+/// | `C`, `U` (Quectel quirks)                      | unmapped  |
+///
+/// The unmapped modes are returned as the error.
+#[cfg(feature = "nmea-v2-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
+impl TryFrom<FaaMode> for Status {
+    type Error = FaaMode;
+
+    fn try_from(value: FaaMode) -> Result<Self, Self::Error> {
+        match value {
+            FaaMode::Autonomous | FaaMode::Differential | FaaMode::FloatRtk | FaaMode::FixedRtk => {
+                Ok(Status::Valid)
+            }
+            #[cfg(feature = "nmea-v4-11")]
+            FaaMode::Precise => Ok(Status::Valid),
+            FaaMode::Estimated | FaaMode::Manual | FaaMode::DataNotValid | FaaMode::Simulator => {
+                Ok(Status::Invalid)
+            }
+            FaaMode::Caution | FaaMode::Unsafe => Err(value),
+        }
+    }
+}
+
+/// Converts a navigation status into a status.
+///
+/// | Navigation status  | Status    |
+/// |--------------------|-----------|
+/// | `A`, `D`           | `Valid`   |
+/// | `E`, `M`, `N`, `S` | `Invalid` |
+/// | `V`                | unmapped  |
+///
+/// `V` does not describe the fix itself (see [`RMC::is_nav_status_consistent`]),
+/// so it is returned as the error.
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl TryFrom<NavStatus> for Status {
+    type Error = NavStatus;
+
+    fn try_from(value: NavStatus) -> Result<Self, Self::Error> {
+        match value {
+            NavStatus::Autonomous | NavStatus::Differential => Ok(Status::Valid),
+            NavStatus::Estimated
+            | NavStatus::Manual
+            | NavStatus::NotValid
+            | NavStatus::Simulator => Ok(Status::Invalid),
+            NavStatus::Valid => Err(value),
+        }
+    }
+}
+
+/// Converts a navigation status into the FAA mode indicator sharing its letter.
+///
+/// `A`, `D`, `E`, `M`, `N` and `S` map to the FAA mode with the same letter;
+/// `V` has no FAA mode counterpart and is returned as the error.
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl TryFrom<NavStatus> for FaaMode {
+    type Error = NavStatus;
+
+    fn try_from(value: NavStatus) -> Result<Self, Self::Error> {
+        match value {
+            NavStatus::Autonomous => Ok(FaaMode::Autonomous),
+            NavStatus::Differential => Ok(FaaMode::Differential),
+            NavStatus::Estimated => Ok(FaaMode::Estimated),
+            NavStatus::Manual => Ok(FaaMode::Manual),
+            NavStatus::NotValid => Ok(FaaMode::DataNotValid),
+            NavStatus::Simulator => Ok(FaaMode::Simulator),
+            NavStatus::Valid => Err(value),
+        }
+    }
+}
+
+/// Converts an FAA mode indicator into the navigation status sharing its letter.
+///
+/// `A`, `D`, `E`, `M`, `N` and `S` map to the navigation status with the same letter;
+/// `C`, `F`, `P`, `R` and `U` have no navigation status counterpart and are returned
+/// as the error.
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl TryFrom<FaaMode> for NavStatus {
+    type Error = FaaMode;
+
+    fn try_from(value: FaaMode) -> Result<Self, Self::Error> {
+        match value {
+            FaaMode::Autonomous => Ok(NavStatus::Autonomous),
+            FaaMode::Differential => Ok(NavStatus::Differential),
+            FaaMode::Estimated => Ok(NavStatus::Estimated),
+            FaaMode::Manual => Ok(NavStatus::Manual),
+            FaaMode::DataNotValid => Ok(NavStatus::NotValid),
+            FaaMode::Simulator => Ok(NavStatus::Simulator),
+            FaaMode::Caution
+            | FaaMode::FloatRtk
+            | FaaMode::Precise
+            | FaaMode::FixedRtk
+            | FaaMode::Unsafe => Err(value),
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
 #[cfg_attr(not(feature = "nmea-v2-3"), nmea(selector(one_of("012"))))]
 #[cfg_attr(feature = "nmea-v2-3", nmea(selector(one_of("012345678"))))]
 /// Quality of the GPS fix
@@ -329,8 +967,38 @@ pub enum Quality {
     Simulation,
 }
 
+/// Converts a fix quality into its numeric value, e.g. `1` for [`Quality::GPSFix`].
+impl From<Quality> for u8 {
+    fn from(value: Quality) -> Self {
+        match value {
+            Quality::NoFix => 0,
+            Quality::GPSFix => 1,
+            Quality::DGPSFix => 2,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::PPSFix => 3,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::RTK => 4,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::FloatRTK => 5,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::Estimated => 6,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::Manual => 7,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::Simulation => 8,
+        }
+    }
+}
+
+/// Converts a fix quality into its selector character, e.g. for re-encoding.
+impl From<Quality> for char {
+    fn from(value: Quality) -> Self {
+        char::from(b'0' + u8::from(value))
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
 #[nmea(selector(one_of("AM")))]
 /// Selection Mode
 pub enum SelectionMode {
@@ -343,8 +1011,18 @@ pub enum SelectionMode {
     Manual,
 }
 
+/// Converts a selection mode into its selector character, e.g. for re-encoding.
+impl From<SelectionMode> for char {
+    fn from(value: SelectionMode) -> Self {
+        match value {
+            SelectionMode::Automatic => 'A',
+            SelectionMode::Manual => 'M',
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
 #[nmea(selector(one_of("123")))]
 /// Fix Mode
 pub enum FixMode {
@@ -360,10 +1038,28 @@ pub enum FixMode {
     Fix3D,
 }
 
+/// Converts a fix mode into its numeric value, e.g. `3` for [`FixMode::Fix3D`].
+impl From<FixMode> for u8 {
+    fn from(value: FixMode) -> Self {
+        match value {
+            FixMode::NoFix => 1,
+            FixMode::Fix2D => 2,
+            FixMode::Fix3D => 3,
+        }
+    }
+}
+
+/// Converts a fix mode into its selector character, e.g. for re-encoding.
+impl From<FixMode> for char {
+    fn from(value: FixMode) -> Self {
+        char::from(b'0' + u8::from(value))
+    }
+}
+
 #[cfg(feature = "nmea-v4-11")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, NmeaParse)]
 #[nmea(selector(one_of("123456")))]
 /// NMEA 4.11 System ID
 ///
@@ -390,24 +1086,323 @@ pub enum SystemId {
     Navic,
 }
 
+/// Converts a system ID into its numeric value, e.g. `1` for [`SystemId::Gps`].
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl From<SystemId> for u8 {
+    fn from(value: SystemId) -> Self {
+        match value {
+            SystemId::Gps => 1,
+            SystemId::Glonass => 2,
+            SystemId::Galileo => 3,
+            SystemId::Beidou => 4,
+            SystemId::Qzss => 5,
+            SystemId::Navic => 6,
+        }
+    }
+}
+
+/// Converts a system ID into its selector character, e.g. for re-encoding.
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl From<SystemId> for char {
+    fn from(value: SystemId) -> Self {
+        char::from(b'0' + u8::from(value))
+    }
+}
+
+/// Converts a GNSS talker ID into its system ID, e.g. [`TalkerId::Galileo`] into
+/// [`SystemId::Galileo`]. Talker IDs of other devices, including the combined
+/// [`TalkerId::Gnss`], are returned as the error.
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl TryFrom<TalkerId> for SystemId {
+    type Error = TalkerId;
+
+    fn try_from(value: TalkerId) -> Result<Self, Self::Error> {
+        match value {
+            TalkerId::Gps => Ok(SystemId::Gps),
+            TalkerId::Glonass => Ok(SystemId::Glonass),
+            TalkerId::Galileo => Ok(SystemId::Galileo),
+            TalkerId::Beidou => Ok(SystemId::Beidou),
+            TalkerId::Qzss => Ok(SystemId::Qzss),
+            TalkerId::Navic => Ok(SystemId::Navic),
+            other => Err(other),
+        }
+    }
+}
+
+/// Defines the signal IDs of a single GNSS system, along with conversions from and
+/// into their raw numeric values.
+macro_rules! signal_id_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $raw:literal,)+
+        }
+    ) => {
+        #[cfg(feature = "nmea-v4-11")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $(#[$meta])*
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+        }
+
+        #[cfg(feature = "nmea-v4-11")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $raw,)+
+                }
+            }
+        }
+
+        /// Converts a raw signal ID into a signal of this system, unassigned values are
+        /// returned as the error.
+        #[cfg(feature = "nmea-v4-11")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+        impl TryFrom<u8> for $name {
+            type Error = u8;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    $($raw => Ok($name::$variant),)+
+                    other => Err(other),
+                }
+            }
+        }
+    };
+}
+
+signal_id_type! {
+    /// NMEA 4.11 GPS Signal ID
+    GpsSignalId {
+        /// 1 - L1 C/A
+        L1Ca = 1,
+        /// 2 - L1 P(Y)
+        L1PY = 2,
+        /// 3 - L1 M
+        L1M = 3,
+        /// 4 - L2 P(Y)
+        L2PY = 4,
+        /// 5 - L2C-M
+        L2cM = 5,
+        /// 6 - L2C-L
+        L2cL = 6,
+        /// 7 - L5-I
+        L5I = 7,
+        /// 8 - L5-Q
+        L5Q = 8,
+    }
+}
+
+signal_id_type! {
+    /// NMEA 4.11 GLONASS Signal ID
+    GlonassSignalId {
+        /// 1 - G1 C/A
+        G1Ca = 1,
+        /// 2 - G1 P
+        G1P = 2,
+        /// 3 - G2 C/A
+        G2Ca = 3,
+        /// 4 - G2 P
+        G2P = 4,
+    }
+}
+
+signal_id_type! {
+    /// NMEA 4.11 Galileo Signal ID
+    GalileoSignalId {
+        /// 1 - E5a
+        E5a = 1,
+        /// 2 - E5b
+        E5b = 2,
+        /// 3 - E5 a+b
+        E5ab = 3,
+        /// 4 - E6-A
+        E6A = 4,
+        /// 5 - E6-BC
+        E6Bc = 5,
+        /// 6 - L1-A
+        L1A = 6,
+        /// 7 - L1-BC
+        L1Bc = 7,
+    }
+}
+
+signal_id_type! {
+    /// NMEA 4.11 BeiDou Signal ID
+    BeidouSignalId {
+        /// 1 - B1I
+        B1I = 1,
+        /// 2 - B1Q
+        B1Q = 2,
+        /// 3 - B1C
+        B1C = 3,
+        /// 4 - B1A
+        B1A = 4,
+        /// 5 - B2-a
+        B2a = 5,
+        /// 6 - B2-b
+        B2b = 6,
+        /// 7 - B2 a+b
+        B2ab = 7,
+        /// 8 - B3I
+        B3I = 8,
+        /// 9 - B3Q
+        B3Q = 9,
+        /// A - B3A
+        B3A = 0xA,
+        /// B - B2I
+        B2I = 0xB,
+        /// C - B2Q
+        B2Q = 0xC,
+    }
+}
+
+signal_id_type! {
+    /// NMEA 4.11 QZSS Signal ID
+    QzssSignalId {
+        /// 1 - L1 C/A
+        L1Ca = 1,
+        /// 2 - L1C (D)
+        L1cD = 2,
+        /// 3 - L1C (P)
+        L1cP = 3,
+        /// 4 - LIS
+        Lis = 4,
+        /// 5 - L2C-M
+        L2cM = 5,
+        /// 6 - L2C-L
+        L2cL = 6,
+        /// 7 - L5-I
+        L5I = 7,
+        /// 8 - L5-Q
+        L5Q = 8,
+        /// 9 - L6D
+        L6D = 9,
+        /// A - L6E
+        L6E = 0xA,
+    }
+}
+
+signal_id_type! {
+    /// NMEA 4.11 NavIC Signal ID
+    NavicSignalId {
+        /// 1 - L5-SPS
+        L5Sps = 1,
+        /// 2 - S-SPS
+        SSps = 2,
+        /// 3 - L5-RS
+        L5Rs = 3,
+        /// 4 - S-RS
+        SRs = 4,
+        /// 5 - L1-SPS
+        L1Sps = 5,
+    }
+}
+
 /// NMEA 4.11 Signal ID
 ///
+/// The same raw signal ID names a different signal in every GNSS system, e.g. `1` is
+/// L1 C/A for GPS but E5a for Galileo, so it can only be resolved together with the
+/// [`SystemId`] of the sentence, see [`SignalId::from_raw`].
+///
 /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_nmea_4_11_system_id_and_signal_id>
 #[cfg(feature = "nmea-v4-11")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
-pub type SignalId = u8;
-/*
- * // TODO:
- * pub enum SignalId {
- *     Gps(GpsSignalId),
- *     Glonass(GlonassSignalId),
- *     Galileo(GalileoSignalId),
- *     Beidou(BeidouSignalId),
- *     Qzss(QzssSignalId),
- *     Navic(NavicSignalId),
- *     Unknown(u8),
- * }
- */
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalId {
+    /// GPS signal
+    Gps(GpsSignalId),
+    /// GLONASS signal
+    Glonass(GlonassSignalId),
+    /// Galileo signal
+    Galileo(GalileoSignalId),
+    /// BeiDou signal
+    Beidou(BeidouSignalId),
+    /// QZSS signal
+    Qzss(QzssSignalId),
+    /// NavIC signal
+    Navic(NavicSignalId),
+    /// Signal ID without a known system, or not assigned within its system
+    Unknown(u8),
+}
+
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl SignalId {
+    /// Resolves a raw signal ID within the given GNSS system.
+    ///
+    /// Without a system, or for a value not assigned within the system, the raw value
+    /// is kept as [`SignalId::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::{
+    ///     GalileoSignalId, GpsSignalId, SignalId, SystemId,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     SignalId::from_raw(Some(SystemId::Gps), 1),
+    ///     SignalId::Gps(GpsSignalId::L1Ca)
+    /// );
+    /// assert_eq!(
+    ///     SignalId::from_raw(Some(SystemId::Galileo), 1),
+    ///     SignalId::Galileo(GalileoSignalId::E5a)
+    /// );
+    /// assert_eq!(SignalId::from_raw(None, 1), SignalId::Unknown(1));
+    /// ```
+    pub fn from_raw(system: Option<SystemId>, raw: u8) -> SignalId {
+        let signal = match system {
+            Some(SystemId::Gps) => GpsSignalId::try_from(raw).map(SignalId::Gps),
+            Some(SystemId::Glonass) => GlonassSignalId::try_from(raw).map(SignalId::Glonass),
+            Some(SystemId::Galileo) => GalileoSignalId::try_from(raw).map(SignalId::Galileo),
+            Some(SystemId::Beidou) => BeidouSignalId::try_from(raw).map(SignalId::Beidou),
+            Some(SystemId::Qzss) => QzssSignalId::try_from(raw).map(SignalId::Qzss),
+            Some(SystemId::Navic) => NavicSignalId::try_from(raw).map(SignalId::Navic),
+            None => Err(raw),
+        };
+
+        signal.unwrap_or_else(SignalId::Unknown)
+    }
+
+    /// Returns the GNSS system of the signal, [`None`] for [`SignalId::Unknown`]
+    pub fn system(&self) -> Option<SystemId> {
+        match self {
+            SignalId::Gps(_) => Some(SystemId::Gps),
+            SignalId::Glonass(_) => Some(SystemId::Glonass),
+            SignalId::Galileo(_) => Some(SystemId::Galileo),
+            SignalId::Beidou(_) => Some(SystemId::Beidou),
+            SignalId::Qzss(_) => Some(SystemId::Qzss),
+            SignalId::Navic(_) => Some(SystemId::Navic),
+            SignalId::Unknown(_) => None,
+        }
+    }
+}
+
+/// Converts a signal ID back into its raw numeric value, e.g. `1` for both
+/// [`GpsSignalId::L1Ca`] and [`GalileoSignalId::E5a`].
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl From<SignalId> for u8 {
+    fn from(value: SignalId) -> Self {
+        match value {
+            SignalId::Gps(signal) => signal.into(),
+            SignalId::Glonass(signal) => signal.into(),
+            SignalId::Galileo(signal) => signal.into(),
+            SignalId::Beidou(signal) => signal.into(),
+            SignalId::Qzss(signal) => signal.into(),
+            SignalId::Navic(signal) => signal.into(),
+            SignalId::Unknown(raw) => raw,
+        }
+    }
+}
 
 /// Satellite information used in [`GSV`] sentences
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -423,6 +1418,97 @@ pub struct Satellite {
     pub snr: Option<u8>,
 }
 
+impl Satellite {
+    /// Valid elevation range in degrees, used by [`Satellite::parse_checked`]
+    pub const ELEVATION_RANGE: RangeInclusive<u8> = 0..=90;
+    /// Valid azimuth range in degrees, used by [`Satellite::parse_checked`]
+    pub const AZIMUTH_RANGE: RangeInclusive<u16> = 0..=359;
+
+    /// Returns `true` if the elevation and azimuth are either missing or within
+    /// [`Satellite::ELEVATION_RANGE`] and [`Satellite::AZIMUTH_RANGE`]
+    pub fn is_in_range(&self) -> bool {
+        self.elevation
+            .is_none_or(|elevation| Self::ELEVATION_RANGE.contains(&elevation))
+            && self
+                .azimuth
+                .is_none_or(|azimuth| Self::AZIMUTH_RANGE.contains(&azimuth))
+    }
+
+    /// Parses a satellite, rejecting out-of-range elevations and azimuths.
+    ///
+    /// Unlike [`Satellite::parse`], which accepts any value fitting the field types, this
+    /// fails with [`ErrorKind::Verify`](nom::error::ErrorKind::Verify) if the satellite is
+    /// not [in range](Satellite::is_in_range). Use [`GSV::parse_checked`] to check all the
+    /// satellites of a sentence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, nmea_content::Satellite};
+    ///
+    /// let result: IResult<_, _> = Satellite::parse_checked("05,45,120,38");
+    /// assert!(result.is_ok());
+    ///
+    /// let result: IResult<_, _> = Satellite::parse_checked("05,99,120,38");
+    /// assert!(result.is_err());
+    /// ```
+    pub fn parse_checked<'a, E>(i: &'a str) -> IResult<&'a str, Self, E>
+    where
+        E: ParseError<&'a str>,
+    {
+        verify(Satellite::parse, Satellite::is_in_range).parse(i)
+    }
+}
+
+/// Precision of the fractional seconds kept when parsing times
+///
+/// Digits beyond the precision are truncated, see
+/// [`time_with_precision`](crate::nmea_content::time_with_precision).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimePrecision {
+    #[default]
+    /// Milliseconds (3 fractional digits)
+    Milliseconds,
+    /// Microseconds (6 fractional digits)
+    Microseconds,
+    /// Nanoseconds (9 fractional digits)
+    Nanoseconds,
+}
+
+/// A `hhmmss.ss` time that remembers how many fractional digits it was sent with
+///
+/// Parsing into [`time::Time`] loses the original formatting, e.g. `123456` and `123456.00`
+/// give the same value. `NmeaTime` keeps the number of fractional digits so that the time
+/// can be re-encoded exactly as received, which logging tools reproducing their input need.
+///
+/// The fraction is kept with nanosecond precision: further digits are encoded as zeros.
+/// A trailing `.` without digits is encoded without the `.`.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, NmeaParse, nmea_content::NmeaTime};
+///
+/// let result: IResult<_, _> = NmeaTime::parse("123456.50");
+/// let (_, time) = result.unwrap();
+/// assert_eq!(time.time.millisecond(), 500);
+/// assert_eq!(time.fractional_digits, 2);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NmeaTime {
+    /// The parsed time, with nanosecond precision
+    pub time: time::Time,
+    /// Number of fractional second digits in the original field
+    pub fractional_digits: u8,
+}
+
+impl From<NmeaTime> for time::Time {
+    fn from(value: NmeaTime) -> Self {
+        value.time
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Location {
@@ -430,10 +1516,555 @@ pub struct Location {
     pub longitude: f64,
 }
 
+impl Location {
+    /// Returns the latitude as whole degrees, decimal minutes and hemisphere (`N` or `S`)
+    ///
+    /// This is the inverse of the `ddmm.mmm,N` conversion done when parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea0183_parser::nmea_content::Location;
+    ///
+    /// let location = Location {
+    ///     latitude: -(48.0 + 7.038 / 60.0),
+    ///     longitude: 0.0,
+    /// };
+    /// let (degrees, minutes, hemisphere) = location.latitude_dms();
+    /// assert_eq!((degrees, hemisphere), (48, 'S'));
+    /// assert!((minutes - 7.038).abs() < 1e-9);
+    /// ```
+    pub fn latitude_dms(&self) -> (u16, f64, char) {
+        Self::to_dms(self.latitude, 'N', 'S')
+    }
+
+    /// Returns the longitude as whole degrees, decimal minutes and hemisphere (`E` or `W`)
+    ///
+    /// This is the inverse of the `dddmm.mmm,E` conversion done when parsing.
+    pub fn longitude_dms(&self) -> (u16, f64, char) {
+        Self::to_dms(self.longitude, 'E', 'W')
+    }
+
+    fn to_dms(value: f64, positive: char, negative: char) -> (u16, f64, char) {
+        let (value, hemisphere) = if value < 0.0 {
+            (-value, negative)
+        } else {
+            (value, positive)
+        };
+        let degrees = value as u16;
+        (degrees, (value - degrees as f64) * 60.0, hemisphere)
+    }
+}
+
+/// Receiver-independent quality of a position fix
+///
+/// Unifies the fix quality indicators reported by the different sentences,
+/// see [`FixInfo`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FixQuality {
+    #[default]
+    /// No fix available, or the data is not valid
+    NoFix,
+    /// Autonomous GNSS fix
+    Autonomous,
+    /// Differential GNSS fix
+    Differential,
+    /// Precise (PPS) fix
+    Precise,
+    /// Real Time Kinematic, float solution
+    FloatRtk,
+    /// Real Time Kinematic, integer (fixed) solution
+    FixedRtk,
+    /// Estimated (dead reckoning) fix
+    Estimated,
+    /// Manual input
+    Manual,
+    /// Simulated fix
+    Simulated,
+}
+
+impl FixQuality {
+    /// Returns `true` if the fix is backed by actual satellite measurements
+    pub fn is_gnss_fix(&self) -> bool {
+        matches!(
+            self,
+            Self::Autonomous | Self::Differential | Self::Precise | Self::FloatRtk | Self::FixedRtk
+        )
+    }
+}
+
+impl From<&Quality> for FixQuality {
+    fn from(value: &Quality) -> Self {
+        match value {
+            Quality::NoFix => FixQuality::NoFix,
+            Quality::GPSFix => FixQuality::Autonomous,
+            Quality::DGPSFix => FixQuality::Differential,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::PPSFix => FixQuality::Precise,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::RTK => FixQuality::FixedRtk,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::FloatRTK => FixQuality::FloatRtk,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::Estimated => FixQuality::Estimated,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::Manual => FixQuality::Manual,
+            #[cfg(feature = "nmea-v2-3")]
+            Quality::Simulation => FixQuality::Simulated,
+        }
+    }
+}
+
+/// Summary of a position fix, independent of the sentence it was reported in
+///
+/// Allows downstream code to handle the fix quality without special-casing
+/// each sentence, e.g. `FixInfo::from(&gga)`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FixInfo {
+    /// Quality of the fix
+    pub quality: FixQuality,
+    /// Number of satellites in use
+    pub satellite_count: Option<u8>,
+    /// Horizontal Dilution of Precision
+    pub hdop: Option<f32>,
+}
+
 #[cfg(test)]
 mod tests {
+    use core::cell::Cell;
+
     use super::*;
-    use crate::IResult;
+    use crate::{Nmea0183ParserBuilder, checksum_bytes};
+
+    #[test]
+    fn test_sentence_type() {
+        let result: IResult<_, _> = NmeaSentence::parse("GPZDA,123456.78,29,02,2024,03,00");
+        let (_, sentence) = result.unwrap();
+        assert_eq!(sentence.sentence_type(), SentenceType::ZDA);
+
+        let result: IResult<_, _> = SentenceType::parse("GSV");
+        assert_eq!(result, Ok(("", SentenceType::GSV)));
+
+        let result: IResult<_, SentenceType> = SentenceType::parse("UNK");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sentence_type_all() {
+        // Both matches are exhaustive, so that a new sentence type cannot be added without
+        // being covered here
+        fn header(sentence_type: SentenceType) -> &'static str {
+            match sentence_type {
+                SentenceType::ALM => "GPALM,",
+                SentenceType::BWC => "GPBWC,",
+                SentenceType::BWR => "GPBWR,",
+                SentenceType::DBT => "SDDBT,",
+                SentenceType::DPT => "SDDPT,",
+                SentenceType::GGA => "GPGGA,",
+                SentenceType::GLL => "GPGLL,",
+                SentenceType::GSA => "GPGSA,",
+                SentenceType::GSV => "GPGSV,",
+                SentenceType::RMC => "GPRMC,",
+                SentenceType::RTE => "GPRTE,",
+                SentenceType::VBW => "VWVBW,",
+                SentenceType::VTG => "GPVTG,",
+                SentenceType::WPL => "GPWPL,",
+                SentenceType::ZDA => "GPZDA,",
+                SentenceType::Proprietary => "PGRME,",
+            }
+        }
+
+        fn sentence(sentence_type: SentenceType) -> NmeaSentence {
+            match sentence_type {
+                SentenceType::ALM => NmeaSentence::ALM(ALM::default()),
+                SentenceType::BWC => NmeaSentence::BWC(BWC::default()),
+                SentenceType::BWR => NmeaSentence::BWR(BWR::default()),
+                SentenceType::DBT => NmeaSentence::DBT(DBT::default()),
+                SentenceType::DPT => NmeaSentence::DPT(DPT::default()),
+                SentenceType::GGA => NmeaSentence::GGA(GGA::default()),
+                SentenceType::GLL => NmeaSentence::GLL(GLL::default()),
+                SentenceType::GSA => NmeaSentence::GSA(GSA::default()),
+                SentenceType::GSV => NmeaSentence::GSV(GSV::default()),
+                SentenceType::RMC => NmeaSentence::RMC(RMC::default()),
+                SentenceType::RTE => NmeaSentence::RTE(RTE::default()),
+                SentenceType::VBW => NmeaSentence::VBW(VBW::default()),
+                SentenceType::VTG => NmeaSentence::VTG(VTG::default()),
+                SentenceType::WPL => NmeaSentence::WPL(WPL::default()),
+                SentenceType::ZDA => NmeaSentence::ZDA(ZDA::default()),
+                SentenceType::Proprietary => NmeaSentence::Proprietary(Proprietary::default()),
+            }
+        }
+
+        let all = SentenceType::all();
+        for (index, &sentence_type) in all.iter().enumerate() {
+            assert!(!all[..index].contains(&sentence_type), "{sentence_type:?}");
+            assert_eq!(sentence(sentence_type).sentence_type(), sentence_type);
+
+            let input = header(sentence_type);
+            let result: IResult<_, _> = peek_sentence_type(input);
+            assert_eq!(result, Ok((input, sentence_type)));
+        }
+        assert_eq!(all.len(), 16);
+    }
+
+    #[test]
+    fn test_proprietary() {
+        let result: IResult<_, _> = NmeaSentence::parse("PGRME,15.0,M,45.0,M,25.0,M");
+        let (_, sentence) = result.unwrap();
+        assert_eq!(sentence.sentence_type(), SentenceType::Proprietary);
+        assert!(matches!(
+            &sentence,
+            NmeaSentence::Proprietary(proprietary)
+                if proprietary.manufacturer == "GRM" && proprietary.data == "E,15.0,M,45.0,M,25.0,M"
+        ));
+
+        let result: IResult<_, _> = NmeaSentence::parse("PUBX,00,081350.00,4717.113210,N");
+        assert!(matches!(
+            result,
+            Ok(("", NmeaSentence::Proprietary(proprietary)))
+                if proprietary.manufacturer == "UBX" && proprietary.data == ",00,081350.00,4717.113210,N"
+        ));
+
+        let result: IResult<_, NmeaSentence> = NmeaSentence::parse("PG");
+        assert!(result.is_err());
+
+        let mut parser = NmeaSentence::parse_filtered(&[SentenceType::Proprietary]);
+        let result: IResult<_, _> = parser("PUBX,00");
+        assert!(matches!(
+            result,
+            Ok(("", Some(NmeaSentence::Proprietary(_))))
+        ));
+
+        let result: IResult<_, _> = parser("GPZDA,123456.78,29,02,2024,03,00");
+        assert_eq!(result, Ok(("", None)));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let cases = [
+            "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+            "GPZDA,123456.78,29,02,2024,03,00",
+            "GPWPL,4917.16,N,12310.64,W,003",
+            "PGRME,15.0,M,45.0,M,25.0,M",
+        ];
+
+        for input in cases {
+            let strict: IResult<_, _> = NmeaSentence::parse(input);
+            let lenient: IResult<_, _> = NmeaSentence::parse_lenient(input);
+            assert_eq!(lenient, strict, "{input:?}");
+        }
+
+        let input = "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,,extra,fields";
+        let result: IResult<_, NmeaSentence> = NmeaSentence::parse(input);
+        assert!(result.is_err());
+
+        let result: IResult<_, _> = NmeaSentence::parse_lenient(input);
+        let (rest, sentence) = result.unwrap();
+        assert_eq!(rest, ",extra,fields");
+        assert_eq!(sentence.sentence_type(), SentenceType::GGA);
+
+        // The system ID of NMEA 4.11 is left unparsed by older versions
+        let input = "GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,4";
+        let result: IResult<_, _> = NmeaSentence::parse_lenient(input);
+        let (rest, sentence) = result.unwrap();
+        assert!(matches!(sentence, NmeaSentence::GSA(gsa) if gsa.vdop == Some(1.47)));
+        if cfg!(feature = "nmea-v4-11") {
+            assert_eq!(rest, "");
+        } else {
+            assert_eq!(rest, ",4");
+        }
+
+        // Only trailing fields are tolerated
+        let result: IResult<_, NmeaSentence> = NmeaSentence::parse_lenient("GPGGA,invalid,data");
+        assert!(result.is_err());
+
+        let result: IResult<_, NmeaSentence> = NmeaSentence::parse_lenient("GPUNK,some,data");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::UnrecognizedMessage(
+                "GPUNK,some,data"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_route_sentences() {
+        use crate::Nmea0183ParserBuilder;
+
+        let mut parser = Nmea0183ParserBuilder::new().build(NmeaSentence::parse);
+
+        let result: IResult<_, _> = parser("$GPWPL,4917.16,N,12310.64,W,003*65\r\n");
+        let (_, sentence) = result.unwrap();
+        assert_eq!(sentence.sentence_type(), SentenceType::WPL);
+        assert!(matches!(&sentence, NmeaSentence::WPL(wpl) if wpl.waypoint_id == "003"));
+
+        let result: IResult<_, _> = parser("$GPRTE,2,1,c,0,W3IWI,DRIVWY,32CEDR*0D\r\n");
+        let (_, sentence) = result.unwrap();
+        let NmeaSentence::RTE(rte) = sentence else {
+            panic!("expected RTE, got {sentence:?}");
+        };
+        assert_eq!(rte.mode, RouteMode::Complete);
+        assert_eq!(rte.waypoints, ["W3IWI", "DRIVWY", "32CEDR"]);
+    }
+
+    #[test]
+    fn test_parse_with_talker() {
+        let cases = [
+            ("GPZDA,123456.78,29,02,2024,03,00", TalkerId::Gps),
+            ("GLZDA,123456.78,29,02,2024,03,00", TalkerId::Glonass),
+            (
+                "GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+                TalkerId::Gnss,
+            ),
+            ("SDDBT,12.34,f,3.76,M,2.05,F", TalkerId::DepthSounder),
+            ("XXDBT,12.34,f,3.76,M,2.05,F", TalkerId::Other(*b"XX")),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = NmeaSentence::parse_with_talker(input);
+            let (_, (talker, sentence)) = result.unwrap();
+            assert_eq!(talker, expected, "{input:?}");
+            assert_eq!(
+                (NmeaSentence::parse(input) as IResult<_, _>).unwrap().1,
+                sentence,
+                "{input:?}"
+            );
+        }
+
+        let result: IResult<_, _> = NmeaSentence::parse_with_talker("G");
+        assert!(result.is_err());
+
+        let result: IResult<_, _> = NmeaSentence::parse_with_talker("G,DBT,12.34,f,3.76,M,2.05,F");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_talker_id_round_trip() {
+        for id in [*b"GP", *b"GL", *b"GA", *b"GB", *b"GQ", *b"GI", *b"GN"] {
+            assert!(!matches!(TalkerId::from(id), TalkerId::Other(_)));
+            assert_eq!(<[u8; 2]>::from(TalkerId::from(id)), id);
+        }
+        for id in [*b"HC", *b"HE", *b"II", *b"IN", *b"SD", *b"P1"] {
+            assert_eq!(<[u8; 2]>::from(TalkerId::from(id)), id);
+        }
+    }
+
+    #[test]
+    fn test_parse_filtered() {
+        let types = [SentenceType::DPT, SentenceType::ZDA];
+        let mut parser = NmeaSentence::parse_filtered(&types);
+
+        let result: IResult<_, _> = parser("GPZDA,123456.78,29,02,2024,03,00");
+        assert!(matches!(result, Ok(("", Some(NmeaSentence::ZDA(_))))));
+
+        // Requested types are fully parsed and validated
+        let result: IResult<_, _> = parser("GPZDA,123519,32,07,2025,,");
+        assert!(result.is_err());
+
+        // Other types are skipped without parsing their fields, even when malformed
+        let result: IResult<_, _> = parser("GPGSV,not,a,valid,gsv");
+        assert_eq!(result, Ok(("", None)));
+
+        let result: IResult<_, _> = parser("GPUNK,some,data,here");
+        assert_eq!(result, Ok(("", None)));
+
+        let result: IResult<_, _> = parser("GP");
+        assert_eq!(result, Ok(("", None)));
+    }
+
+    #[test]
+    fn test_parse_filtered_stream() {
+        let log = [
+            "GPGSV,3,1,12,01,40,083,45",
+            "GPZDA,123456.78,29,02,2024,03,00",
+            "GPGSV,not,a,valid,gsv",
+            "GPGGA,not,a,valid,gga",
+            "GPZDA,123519,32,07,2025,,",
+        ]
+        .map(|content| format!("${content}*{:02X}\r\n", checksum_bytes(content.as_bytes())))
+        .concat();
+        let log = format!("{log}$GPGSV,3,3,12*00\r\n");
+
+        // Counts the sentences reaching the full sentence parser
+        let parsed = Cell::new(0);
+        let tap = |i| {
+            parsed.set(parsed.get() + 1);
+            NmeaSentence::parse(i)
+        };
+
+        let types = [SentenceType::ZDA];
+        let results: Vec<Result<_, Error<_, nom::error::Error<_>>>> = Nmea0183ParserBuilder::new()
+            .build_iter(filtered(&types, tap), &log)
+            .collect();
+
+        assert!(matches!(
+            results[..],
+            [
+                Ok(None),
+                Ok(Some(NmeaSentence::ZDA(_))),
+                Ok(None),
+                Ok(None),
+                Err(Error::InvalidField(_)),
+                Err(Error::ChecksumMismatch { .. }),
+            ]
+        ));
+        // Only the requested ZDA sentences were fully parsed, the framing of every sentence
+        // is still validated
+        assert_eq!(parsed.get(), 2);
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let expected = Location {
+            latitude: 48.0 + 7.038 / 60.0,
+            longitude: 11.0 + 31.0 / 60.0,
+        };
+        let time = time::Time::from_hms(12, 35, 19).unwrap();
+
+        let cases = [
+            "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+            "GPGLL,4807.038,N,01131.000,E,123519,A,A",
+            "GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W,A",
+        ];
+
+        for input in cases {
+            assert_eq!(
+                parse_position(input),
+                Some((expected.clone(), time)),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_location_dms_round_trip() {
+        let cases = [
+            (
+                "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+                (48, 7.038, 'N'),
+                (11, 31.0, 'E'),
+            ),
+            (
+                "GPGGA,123519,3351.123,S,15112.456,W,1,08,0.9,545.4,M,46.9,M,,",
+                (33, 51.123, 'S'),
+                (151, 12.456, 'W'),
+            ),
+            (
+                "GPGGA,123519,0000.000,N,00000.000,E,1,08,0.9,545.4,M,46.9,M,,",
+                (0, 0.0, 'N'),
+                (0, 0.0, 'E'),
+            ),
+        ];
+
+        for (input, latitude, longitude) in cases {
+            let (location, _) = parse_position(input).unwrap();
+
+            let (degrees, minutes, hemisphere) = location.latitude_dms();
+            assert_eq!((degrees, hemisphere), (latitude.0, latitude.2), "{input:?}");
+            assert!((minutes - latitude.1).abs() < 1e-9, "{input:?}");
+
+            let (degrees, minutes, hemisphere) = location.longitude_dms();
+            assert_eq!(
+                (degrees, hemisphere),
+                (longitude.0, longitude.2),
+                "{input:?}"
+            );
+            assert!((minutes - longitude.1).abs() < 1e-9, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_position_none() {
+        // Other sentence types
+        assert!(parse_position("GPZDA,123456.78,29,02,2024,03,00").is_none());
+        assert!(parse_position("GPXYZ,123519,4807.038,N,01131.000,E").is_none());
+        // Empty fields
+        assert!(
+            parse_position("GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,").is_none()
+        );
+        assert!(parse_position("GPGLL,,,,,123519,V,N").is_none());
+        // Malformed fields
+        assert!(parse_position("GPRMC,123519,A,4807.038,X,01131.000,E,,,230394,,,A").is_none());
+    }
+
+    fn assert_char_round_trip<T>(selectors: &str)
+    where
+        T: for<'a> NmeaParse<&'a str, nom::error::Error<&'a str>> + Into<char> + core::fmt::Debug,
+    {
+        for c in selectors.chars() {
+            let input = c.to_string();
+            let result: IResult<_, T> = T::parse(input.as_str());
+            let (_, value) = result.unwrap();
+            assert_eq!(value.into(), c, "{c:?}");
+        }
+    }
+
+    #[test]
+    fn test_enums_char_round_trip() {
+        assert_char_round_trip::<Status>("AV");
+        assert_char_round_trip::<SelectionMode>("AM");
+        assert_char_round_trip::<FixMode>("123");
+
+        #[cfg(not(feature = "nmea-v2-3"))]
+        assert_char_round_trip::<Quality>("012");
+        #[cfg(feature = "nmea-v2-3")]
+        assert_char_round_trip::<Quality>("012345678");
+
+        #[cfg(all(feature = "nmea-v2-3", not(feature = "nmea-v4-11")))]
+        assert_char_round_trip::<FaaMode>("ACDEFMNRSU");
+        #[cfg(feature = "nmea-v4-11")]
+        {
+            assert_char_round_trip::<FaaMode>("ACDEFMNPRSU");
+            assert_char_round_trip::<NavStatus>("ADEMNSV");
+            assert_char_round_trip::<SystemId>("123456");
+        }
+
+        assert_eq!(u8::from(Quality::DGPSFix), 2);
+        assert_eq!(u8::from(FixMode::Fix3D), 3);
+        #[cfg(feature = "nmea-v4-11")]
+        assert_eq!(u8::from(SystemId::Navic), 6);
+    }
+
+    #[test]
+    fn test_enums_hash() {
+        use std::collections::HashSet;
+
+        let types: HashSet<_> = [SentenceType::GGA, SentenceType::RMC, SentenceType::GGA].into();
+        assert_eq!(types.len(), 2);
+        assert!(types.contains(&SentenceType::RMC));
+
+        let status: HashSet<_> = [Status::Valid, Status::Invalid, Status::Valid].into();
+        assert_eq!(status.len(), 2);
+
+        let quality: HashSet<_> = [Quality::NoFix, Quality::GPSFix, Quality::DGPSFix].into();
+        assert_eq!(quality.len(), 3);
+
+        let modes: HashSet<_> = [(SelectionMode::default(), FixMode::default())].into();
+        assert!(modes.contains(&(SelectionMode::default(), FixMode::default())));
+
+        #[cfg(feature = "nmea-v2-3")]
+        {
+            let faa: HashSet<_> = [FaaMode::Autonomous, FaaMode::Differential].into();
+            assert!(faa.contains(&FaaMode::Autonomous));
+        }
+
+        #[cfg(feature = "nmea-v4-11")]
+        {
+            let nav: HashSet<_> = [NavStatus::Autonomous, NavStatus::Autonomous].into();
+            assert_eq!(nav.len(), 1);
+
+            let systems: HashSet<_> = [SystemId::Gps, SystemId::Galileo].into();
+            assert!(systems.contains(&SystemId::Galileo));
+        }
+
+        // Copy
+        let status = Status::Valid;
+        let copy = status;
+        assert_eq!(status, copy);
+    }
 
     #[test]
     fn test_status() {
@@ -507,6 +2138,114 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "nmea-v2-3")]
+    #[test]
+    fn test_faa_mode_to_status() {
+        assert_eq!(Status::try_from(FaaMode::Autonomous), Ok(Status::Valid));
+        assert_eq!(Status::try_from(FaaMode::Differential), Ok(Status::Valid));
+        assert_eq!(Status::try_from(FaaMode::FloatRtk), Ok(Status::Valid));
+        assert_eq!(Status::try_from(FaaMode::FixedRtk), Ok(Status::Valid));
+        #[cfg(feature = "nmea-v4-11")]
+        assert_eq!(Status::try_from(FaaMode::Precise), Ok(Status::Valid));
+        assert_eq!(Status::try_from(FaaMode::Estimated), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(FaaMode::Manual), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(FaaMode::DataNotValid), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(FaaMode::Simulator), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(FaaMode::Caution), Err(FaaMode::Caution));
+        assert_eq!(Status::try_from(FaaMode::Unsafe), Err(FaaMode::Unsafe));
+    }
+
+    #[cfg(feature = "nmea-v4-11")]
+    #[test]
+    fn test_nav_status_to_status() {
+        assert_eq!(Status::try_from(NavStatus::Autonomous), Ok(Status::Valid));
+        assert_eq!(Status::try_from(NavStatus::Differential), Ok(Status::Valid));
+        assert_eq!(Status::try_from(NavStatus::Estimated), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(NavStatus::Manual), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(NavStatus::NotValid), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(NavStatus::Simulator), Ok(Status::Invalid));
+        assert_eq!(Status::try_from(NavStatus::Valid), Err(NavStatus::Valid));
+    }
+
+    #[cfg(feature = "nmea-v4-11")]
+    #[test]
+    fn test_nav_status_faa_mode_conversions() {
+        let pairs = [
+            (NavStatus::Autonomous, FaaMode::Autonomous),
+            (NavStatus::Differential, FaaMode::Differential),
+            (NavStatus::Estimated, FaaMode::Estimated),
+            (NavStatus::Manual, FaaMode::Manual),
+            (NavStatus::NotValid, FaaMode::DataNotValid),
+            (NavStatus::Simulator, FaaMode::Simulator),
+        ];
+
+        for (nav_status, faa_mode) in pairs {
+            assert_eq!(FaaMode::try_from(nav_status), Ok(faa_mode));
+            assert_eq!(NavStatus::try_from(faa_mode), Ok(nav_status));
+        }
+
+        assert_eq!(FaaMode::try_from(NavStatus::Valid), Err(NavStatus::Valid));
+
+        let unmapped = [
+            FaaMode::Caution,
+            FaaMode::FloatRtk,
+            FaaMode::Precise,
+            FaaMode::FixedRtk,
+            FaaMode::Unsafe,
+        ];
+
+        for faa_mode in unmapped {
+            assert_eq!(NavStatus::try_from(faa_mode), Err(faa_mode));
+        }
+    }
+
+    #[cfg(feature = "nmea-v4-11")]
+    #[test]
+    fn test_signal_id_from_raw() {
+        let gps = SignalId::from_raw(Some(SystemId::Gps), 1);
+        let galileo = SignalId::from_raw(Some(SystemId::Galileo), 1);
+        assert_eq!(gps, SignalId::Gps(GpsSignalId::L1Ca));
+        assert_eq!(galileo, SignalId::Galileo(GalileoSignalId::E5a));
+        assert_ne!(gps, galileo);
+        assert_eq!(u8::from(gps), u8::from(galileo));
+
+        assert_eq!(
+            SignalId::from_raw(Some(SystemId::Beidou), 0xC),
+            SignalId::Beidou(BeidouSignalId::B2Q)
+        );
+        assert_eq!(
+            SignalId::from_raw(Some(SystemId::Qzss), 0xA),
+            SignalId::Qzss(QzssSignalId::L6E)
+        );
+        assert_eq!(
+            SignalId::from_raw(Some(SystemId::Glonass), 5),
+            SignalId::Unknown(5)
+        );
+        assert_eq!(SignalId::from_raw(None, 1), SignalId::Unknown(1));
+        assert_eq!(SignalId::Unknown(1).system(), None);
+        assert_eq!(
+            SignalId::Navic(NavicSignalId::L1Sps).system(),
+            Some(SystemId::Navic)
+        );
+
+        for system in [
+            SystemId::Gps,
+            SystemId::Glonass,
+            SystemId::Galileo,
+            SystemId::Beidou,
+            SystemId::Qzss,
+            SystemId::Navic,
+        ] {
+            for raw in 0..=0xF {
+                let signal = SignalId::from_raw(Some(system), raw);
+                assert_eq!(u8::from(signal), raw);
+            }
+        }
+
+        assert_eq!(SystemId::try_from(TalkerId::Qzss), Ok(SystemId::Qzss));
+        assert_eq!(SystemId::try_from(TalkerId::Gnss), Err(TalkerId::Gnss));
+    }
+
     #[cfg(feature = "nmea-v4-11")]
     #[test]
     fn test_gsa_quality() {
@@ -0,0 +1,117 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use nom::{Parser, bytes::complete::tag, sequence::preceded};
+
+use crate::{
+    self as nmea0183_parser, IResult, NmeaParse,
+    nmea_content::{Proprietary, parse::with_unit},
+};
+
+/// PGRME - Garmin Estimated Error Information
+///
+/// ```text
+///         1   2 3   4 5   6
+///         |   | |   | |   |
+///  $PGRME,x.x,M,x.x,M,x.x,M*hh<CR><LF>
+/// ```
+///
+/// The sentence is received as a [`Proprietary`] sentence, see [`Proprietary::pgrme`].
+/// [`PGRME::parse`](NmeaParse::parse) parses the fields following `PGRME,`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct PGRME {
+    #[nmea(parser(with_unit('M')))]
+    /// Estimated horizontal position error in meters
+    pub horizontal_error: Option<f32>,
+    #[nmea(parser(with_unit('M')))]
+    /// Estimated vertical position error in meters
+    pub vertical_error: Option<f32>,
+    #[nmea(parser(with_unit('M')))]
+    /// Estimated spherical position error in meters
+    pub spherical_error: Option<f32>,
+}
+
+impl Proprietary {
+    /// Parses a Garmin `PGRME` sentence from its proprietary content.
+    ///
+    /// Returns [`None`] if the sentence is not a `PGRME` sentence, or if any of its
+    /// fields is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, Nmea0183ParserBuilder, NmeaParse, nmea_content::NmeaSentence};
+    /// use nom::Parser;
+    ///
+    /// let mut parser = Nmea0183ParserBuilder::new().build(NmeaSentence::parse);
+    /// let result: IResult<_, _> = parser.parse("$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n");
+    ///
+    /// let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+    ///     panic!("Expected a proprietary sentence");
+    /// };
+    /// let pgrme = proprietary.pgrme().unwrap();
+    /// assert_eq!(pgrme.horizontal_error, Some(15.0));
+    /// assert_eq!(pgrme.spherical_error, Some(25.0));
+    /// ```
+    pub fn pgrme(&self) -> Option<PGRME> {
+        if self.manufacturer != "GRM" {
+            return None;
+        }
+
+        let result: IResult<_, _> = preceded(tag("E,"), PGRME::parse).parse(self.data.as_str());
+        result.ok().map(|(_, pgrme)| pgrme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nmea_content::NmeaSentence;
+
+    #[test]
+    fn test_pgrme_parsing() {
+        let result: IResult<_, _> = NmeaSentence::parse("PGRME,15.0,M,45.0,M,25.0,M");
+        let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+            panic!("Expected a proprietary sentence: {result:?}");
+        };
+
+        assert_eq!(
+            proprietary.pgrme(),
+            Some(PGRME {
+                horizontal_error: Some(15.0),
+                vertical_error: Some(45.0),
+                spherical_error: Some(25.0),
+            })
+        );
+    }
+
+    #[test]
+    fn test_pgrme_empty_fields() {
+        for input in ["PGRME,,M,,M,,M", "PGRME,,,,,,"] {
+            let result: IResult<_, _> = NmeaSentence::parse(input);
+            let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+                panic!("Expected a proprietary sentence: {result:?}");
+            };
+            assert_eq!(proprietary.pgrme(), Some(PGRME::default()), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_pgrme_other_sentences() {
+        let cases = [
+            "PGRMZ,246,f,3",
+            "PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3",
+            "PGRME,15.0,M,45.0,M,x,M",
+        ];
+
+        for input in cases {
+            let result: IResult<_, _> = NmeaSentence::parse(input);
+            let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+                panic!("Expected a proprietary sentence: {result:?}");
+            };
+            assert_eq!(proprietary.pgrme(), None, "{input:?}");
+        }
+    }
+}
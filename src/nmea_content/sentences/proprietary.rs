@@ -0,0 +1,91 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use nom::{
+    Parser,
+    bytes::complete::take,
+    combinator::verify,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{IResult, NmeaParse};
+
+/// Proprietary sentence
+///
+/// Proprietary sentences replace the talker ID by a `P` followed by a three-character
+/// manufacturer mnemonic code, e.g. `$PGRME` for Garmin or `$PUBX` for u-blox. Their
+/// content is manufacturer specific, so it is kept raw for vendor extensions to parse.
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_talker_ids>
+///
+/// ```text
+///   1   2
+///   |   |
+///  $Pccc<data>*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Proprietary {
+    /// Manufacturer mnemonic code, e.g. `GRM` for Garmin
+    pub manufacturer: heapless::String<3>,
+    /// Raw content following the manufacturer code, e.g. `E,15.0,M,45.0,M,25.0,M` for a
+    /// `$PGRME` sentence or `,00,...` for a `$PUBX` sentence
+    pub data: heapless::String<128>,
+}
+
+impl<'a, E> NmeaParse<&'a str, E> for Proprietary
+where
+    E: ParseError<&'a str>,
+{
+    fn parse(i: &'a str) -> IResult<&'a str, Self, E> {
+        let (data, manufacturer) = verify(take(3u8), |code: &str| {
+            code.bytes().all(|b| b.is_ascii_alphanumeric())
+        })
+        .parse(i)?;
+
+        let too_large = |_| nom::Err::Error(nom::error::make_error(data, ErrorKind::TooLarge));
+        let proprietary = Proprietary {
+            manufacturer: manufacturer.try_into().map_err(too_large)?,
+            data: data.try_into().map_err(too_large)?,
+        };
+
+        Ok((&data[data.len()..], proprietary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_proprietary_parsing() {
+        let result: IResult<_, _> = Proprietary::parse("GRME,15.0,M,45.0,M,25.0,M");
+        let (rest, proprietary) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(proprietary.manufacturer, "GRM");
+        assert_eq!(proprietary.data, "E,15.0,M,45.0,M,25.0,M");
+
+        let result: IResult<_, _> = Proprietary::parse("UBX,00");
+        let (_, proprietary) = result.unwrap();
+        assert_eq!(proprietary.manufacturer, "UBX");
+        assert_eq!(proprietary.data, ",00");
+
+        let result: IResult<_, _> = Proprietary::parse("UBX");
+        let (_, proprietary) = result.unwrap();
+        assert_eq!(proprietary.data, "");
+
+        let result: IResult<_, Proprietary> = Proprietary::parse("U,B");
+        assert!(result.is_err());
+
+        let data = format!("UBX{}", ",0".repeat(65));
+        let result: IResult<_, Proprietary> = Proprietary::parse(&data);
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(crate::Error::ParsingError(
+                nom::error::Error::new(&data[3..], ErrorKind::TooLarge)
+            )))
+        );
+    }
+}
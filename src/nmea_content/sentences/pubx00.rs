@@ -0,0 +1,180 @@
+use core::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use nom::{
+    Parser,
+    bytes::complete::{tag, take_while_m_n},
+    combinator::map,
+    error::ParseError,
+    sequence::preceded,
+};
+
+use crate::{
+    self as nmea0183_parser, IResult, NmeaParse,
+    nmea_content::{Location, Proprietary, parse::location},
+};
+
+use super::gga::age_of_dgps;
+
+/// PUBX,00 - u-blox Lat/Long Position Data
+///
+/// Documented in the u-blox 8 / u-blox M8 Receiver Description (UBX-13003221).
+///
+/// ```text
+///                                                                                              18
+///           1         2          3 4           5 6   7  8   9   10  11  12  13  14  15  16  17 | 19
+///           |         |          | |           | |   |  |   |   |   |   |   |   |   |   |   |  | |
+///  $PUBX,00,hhmmss.ss,ddmm.mmmmm,a,dddmm.mmmmm,a,x.x,cc,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,xx,x,x*hh<CR><LF>
+/// ```
+///
+/// The sentence is received as a [`Proprietary`] sentence, see [`Proprietary::pubx00`].
+/// [`PUBX00::parse`](NmeaParse::parse) parses the fields following `PUBX,00,`, leaving the
+/// two reserved fields at the end of the sentence unparsed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct PUBX00 {
+    /// Fix time in UTC
+    pub time: Option<time::Time>,
+    #[nmea(parser(location))]
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Altitude above user datum ellipsoid in meters
+    pub altitude: Option<f32>,
+    #[nmea(parser(nav_status))]
+    /// Navigation status, e.g. `NF` for no fix, `G3` for a standalone 3D fix or `D3` for a
+    /// differential 3D fix
+    pub nav_status: heapless::String<2>,
+    /// Horizontal accuracy estimate in meters
+    pub horizontal_accuracy: Option<f32>,
+    /// Vertical accuracy estimate in meters
+    pub vertical_accuracy: Option<f32>,
+    /// Speed over ground in km/h
+    pub speed_over_ground: Option<f32>,
+    /// Course over ground in degrees
+    pub course_over_ground: Option<f32>,
+    /// Vertical velocity in m/s, positive downwards
+    pub vertical_velocity: Option<f32>,
+    #[nmea(parser(age_of_dgps))]
+    /// Age of differential corrections, null field when no corrections are used
+    pub age_of_corrections: Option<Duration>,
+    /// Horizontal Dilution of Precision
+    pub hdop: Option<f32>,
+    /// Vertical Dilution of Precision
+    pub vdop: Option<f32>,
+    /// Time Dilution of Precision
+    pub tdop: Option<f32>,
+    /// Number of satellites used in the navigation solution
+    pub satellite_count: Option<u8>,
+}
+
+impl Proprietary {
+    /// Parses a u-blox `PUBX,00` sentence from its proprietary content.
+    ///
+    /// Returns [`None`] if the sentence is not a `PUBX,00` sentence, or if any of its
+    /// fields is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, Nmea0183ParserBuilder, NmeaParse, nmea_content::NmeaSentence};
+    /// use nom::Parser;
+    ///
+    /// let mut parser = Nmea0183ParserBuilder::new().build(NmeaSentence::parse);
+    /// let result: IResult<_, _> = parser.parse(
+    ///     "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n",
+    /// );
+    ///
+    /// let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+    ///     panic!("Expected a proprietary sentence");
+    /// };
+    /// let pubx00 = proprietary.pubx00().unwrap();
+    /// assert_eq!(pubx00.nav_status, "G3");
+    /// assert_eq!(pubx00.satellite_count, Some(9));
+    /// ```
+    pub fn pubx00(&self) -> Option<PUBX00> {
+        if self.manufacturer != "UBX" {
+            return None;
+        }
+
+        let result: IResult<_, _> = preceded(tag(",00,"), PUBX00::parse).parse(self.data.as_str());
+        result.ok().map(|(_, pubx00)| pubx00)
+    }
+}
+
+fn nav_status<'a, E>(i: &'a str) -> IResult<&'a str, heapless::String<2>, E>
+where
+    E: ParseError<&'a str>,
+{
+    // Both characters are ASCII, so the status always fits
+    map(
+        take_while_m_n(2, 2, |c: char| c.is_ascii_alphanumeric()),
+        |status: &str| status.try_into().unwrap_or_default(),
+    )
+    .parse(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nmea_content::NmeaSentence;
+
+    #[test]
+    fn test_pubx00_parsing() {
+        let result: IResult<_, _> = NmeaSentence::parse(
+            "PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0",
+        );
+        let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+            panic!("Expected a proprietary sentence: {result:?}");
+        };
+        let pubx00 = proprietary.pubx00().unwrap();
+
+        assert_eq!(pubx00.time, time::Time::from_hms(8, 13, 50).ok());
+        let location = pubx00.location.unwrap();
+        assert!((location.latitude - (47.0 + 17.113210 / 60.0)).abs() < 1e-9);
+        assert!((location.longitude - (8.0 + 33.915187 / 60.0)).abs() < 1e-9);
+        assert_eq!(pubx00.altitude, Some(546.589));
+        assert_eq!(pubx00.nav_status, "G3");
+        assert_eq!(pubx00.horizontal_accuracy, Some(2.1));
+        assert_eq!(pubx00.vertical_accuracy, Some(2.0));
+        assert_eq!(pubx00.speed_over_ground, Some(0.007));
+        assert_eq!(pubx00.course_over_ground, Some(77.52));
+        assert_eq!(pubx00.vertical_velocity, Some(0.007));
+        assert_eq!(pubx00.age_of_corrections, None);
+        assert_eq!(pubx00.hdop, Some(0.92));
+        assert_eq!(pubx00.vdop, Some(1.19));
+        assert_eq!(pubx00.tdop, Some(0.77));
+        assert_eq!(pubx00.satellite_count, Some(9));
+    }
+
+    #[test]
+    fn test_pubx00_no_fix() {
+        let result: IResult<_, _> = PUBX00::parse(
+            "000000.00,0000.00000,N,00000.00000,E,0.000,NF,5303302,3750001,0.000,0.00,0.000,,99.99,99.99,99.99,0,0,0",
+        );
+        let (rest, pubx00) = result.unwrap();
+        assert_eq!(rest, ",0,0");
+        assert_eq!(pubx00.nav_status, "NF");
+        assert_eq!(pubx00.horizontal_accuracy, Some(5303302.0));
+        assert_eq!(pubx00.satellite_count, Some(0));
+    }
+
+    #[test]
+    fn test_pubx00_other_sentences() {
+        let cases = [
+            "PUBX,03,11,23,-,,,45,010,29,-,,,46,013",
+            "PGRME,15.0,M,45.0,M,25.0,M",
+            "PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52",
+        ];
+
+        for input in cases {
+            let result: IResult<_, _> = NmeaSentence::parse(input);
+            let Ok((_, NmeaSentence::Proprietary(proprietary))) = result else {
+                panic!("Expected a proprietary sentence: {result:?}");
+            };
+            assert_eq!(proprietary.pubx00(), None, "{input:?}");
+        }
+    }
+}
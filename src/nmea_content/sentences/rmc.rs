@@ -1,23 +1,20 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use nom::{
-    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
-    branch::alt,
-    character::complete::{char, one_of},
-    combinator::value,
-    error::ParseError,
-    sequence::separated_pair,
-};
+#[cfg(feature = "nmea-v4-11")]
+use nom::error::ParseError;
 
 #[cfg(feature = "nmea-v2-3")]
 use crate::nmea_content::FaaMode;
-#[cfg(feature = "nmea-v4-11")]
-use crate::nmea_content::NavStatus;
 use crate::{
-    self as nmea0183_parser, IResult, NmeaParse,
-    nmea_content::{Location, Status, parse::location},
+    self as nmea0183_parser, NmeaParse,
+    nmea_content::{
+        Location, Status,
+        parse::{location, magnetic_variation},
+    },
 };
+#[cfg(feature = "nmea-v4-11")]
+use crate::{Error, IResult, nmea_content::NavStatus};
 
 /// RMC - Recommended Minimum Navigation Information
 ///
@@ -72,25 +69,83 @@ pub struct RMC {
     pub nav_status: Option<NavStatus>,
 }
 
-pub fn magnetic_variation<I, E>(i: I) -> IResult<I, Option<f32>, E>
-where
-    I: Input + Offset + ParseTo<f32> + AsBytes,
-    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
-    <I as Input>::Item: AsChar,
-    <I as Input>::Iter: Clone,
-    E: ParseError<I>,
-{
-    alt((
-        value(None, char(',')),
-        separated_pair(f32::parse, char(','), one_of("EW")).map(|(value, dir)| {
-            if dir == 'W' {
-                Some(-value)
-            } else {
-                Some(value)
-            }
-        }),
-    ))
-    .parse(i)
+#[cfg(feature = "nmea-v4-11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+impl RMC {
+    /// Checks whether the navigation status agrees with the FAA mode indicator.
+    ///
+    /// Both fields describe how the fix was obtained, so a receiver should never
+    /// report e.g. an autonomous fix in one and "not valid" in the other.
+    ///
+    /// | Navigation status  | Consistent FAA modes                                     |
+    /// |--------------------|----------------------------------------------------------|
+    /// | `A` - Autonomous   | `A`                                                      |
+    /// | `D` - Differential | `D`, `F`, `R`, `P`                                       |
+    /// | `E` - Estimated    | `E`                                                      |
+    /// | `M` - Manual       | `M`                                                      |
+    /// | `S` - Simulator    | `S`                                                      |
+    /// | `N` - Not valid    | `N`                                                      |
+    /// | `V`                | any, the receiver does not provide a navigational status |
+    ///
+    /// The Quectel `C` (caution) and `U` (unsafe) FAA modes do not describe how the
+    /// fix was obtained, so they are consistent with any navigation status except `N`.
+    /// If either field is missing, there is nothing to compare and `true` is returned.
+    pub fn is_nav_status_consistent(&self) -> bool {
+        let (Some(nav_status), Some(faa_mode)) = (&self.nav_status, &self.faa_mode) else {
+            return true;
+        };
+
+        match (nav_status, faa_mode) {
+            (NavStatus::Valid, _) => true,
+            (NavStatus::NotValid, faa_mode) => *faa_mode == FaaMode::DataNotValid,
+            (_, FaaMode::DataNotValid) => false,
+            (_, FaaMode::Caution | FaaMode::Unsafe) => true,
+            (NavStatus::Autonomous, faa_mode) => *faa_mode == FaaMode::Autonomous,
+            (NavStatus::Differential, faa_mode) => matches!(
+                faa_mode,
+                FaaMode::Differential | FaaMode::FloatRtk | FaaMode::FixedRtk | FaaMode::Precise
+            ),
+            (NavStatus::Estimated, faa_mode) => *faa_mode == FaaMode::Estimated,
+            (NavStatus::Manual, faa_mode) => *faa_mode == FaaMode::Manual,
+            (NavStatus::Simulator, faa_mode) => *faa_mode == FaaMode::Simulator,
+        }
+    }
+
+    /// Parses RMC content like [`NmeaParse::parse`], additionally rejecting sentences
+    /// whose navigation status contradicts the FAA mode indicator.
+    ///
+    /// See [`RMC::is_nav_status_consistent`] for the accepted combinations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidField`] with the whole content as input if the fields
+    /// are contradictory, or the regular parsing error if the content is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, nmea_content::RMC};
+    ///
+    /// let result: IResult<_, _> =
+    ///     RMC::parse_consistent("001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,A");
+    /// assert!(result.is_ok());
+    ///
+    /// let result: IResult<_, _> =
+    ///     RMC::parse_consistent("001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,N");
+    /// assert!(result.is_err());
+    /// ```
+    pub fn parse_consistent<'a, E>(i: &'a str) -> IResult<&'a str, Self, E>
+    where
+        E: ParseError<&'a str>,
+    {
+        let (rest, rmc) = <Self as NmeaParse<&'a str, E>>::parse(i)?;
+
+        if !rmc.is_nav_status_consistent() {
+            return Err(nom::Err::Error(Error::InvalidField(i)));
+        }
+
+        Ok((rest, rmc))
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +162,46 @@ mod tests {
             assert!(result.is_ok(), "Failed: {input:?}\n\t{result:?}");
         }
     }
+
+    #[cfg(feature = "nmea-v4-11")]
+    #[test]
+    fn test_rmc_nav_status_consistency() {
+        let consistent = [
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,A",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,V",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,R,D",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,E,E",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,C,A",
+            "001031.00,V,,,,,,,100117,,,N,N",
+            "001031.00,V,,,,,,,100117,,,N,V",
+            "001031.00,V,,,,,,,100117,,,N,",
+            "001031.00,V,,,,,,,100117,,,,N",
+        ];
+
+        for &input in &consistent {
+            let result: IResult<_, _> = RMC::parse_consistent(input);
+            assert!(result.is_ok(), "Failed: {input:?}\n\t{result:?}");
+        }
+
+        let contradictory = [
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,N",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,D",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,D,A",
+            "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,S,M",
+            "001031.00,V,,,,,,,100117,,,N,A",
+            "001031.00,V,,,,,,,100117,,,N,E",
+        ];
+
+        for &input in &contradictory {
+            let result: IResult<_, _> = RMC::parse_consistent(input);
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(Error::InvalidField(input))),
+                "Failed: {input:?}"
+            );
+
+            let result: IResult<_, _> = RMC::parse(input);
+            assert!(result.is_ok(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
 }
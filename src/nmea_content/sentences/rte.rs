@@ -0,0 +1,65 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaParse,
+    nmea_content::{RouteMode, WaypointId},
+};
+
+/// RTE - Routes
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rte_routes>
+///
+/// ```text
+///         1   2   3 4    5           x    n
+///         |   |   | |    |           |    |
+///  $--RTE,x.x,x.x,a,c--c,c--c, ..... c--c*hh<CR><LF>
+/// ```
+///
+/// Long routes are split over several sentences, each listing some of the waypoints. Up to
+/// 12 waypoints are kept per sentence, see the capacity overflow section of the crate docs.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct RTE {
+    /// Total number of RTE sentences to be transmitted for this route
+    pub total_messages: u8,
+    /// Sentence number of this RTE message within current route
+    pub message_number: u8,
+    /// Whether the sentences list the complete route or the working route
+    pub mode: RouteMode,
+    /// Route identifier
+    pub route_id: Option<WaypointId>,
+    /// Waypoint identifiers, in order along the route
+    pub waypoints: heapless::Vec<WaypointId, 12>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_rte_parsing() {
+        let result: IResult<_, _> = RTE::parse("2,1,c,0,W3IWI,DRIVWY,32CEDR");
+        let (rest, rte) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(rte.total_messages, 2);
+        assert_eq!(rte.message_number, 1);
+        assert_eq!(rte.mode, RouteMode::Complete);
+        assert_eq!(rte.route_id.as_deref(), Some("0"));
+        assert_eq!(rte.waypoints, ["W3IWI", "DRIVWY", "32CEDR"]);
+
+        let result: IResult<_, _> = RTE::parse("1,1,w,,START");
+        let (_, rte) = result.unwrap();
+        assert_eq!(rte.mode, RouteMode::Working);
+        assert_eq!(rte.route_id, None);
+        assert_eq!(rte.waypoints, ["START"]);
+
+        let result: IResult<_, _> = RTE::parse("1,1,c,0");
+        assert!(result.unwrap().1.waypoints.is_empty());
+
+        let result: IResult<_, RTE> = RTE::parse("1,1,x,0,START");
+        assert!(result.is_err());
+    }
+}
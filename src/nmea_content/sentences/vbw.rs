@@ -0,0 +1,121 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaParse, nmea_content::Status};
+
+/// VBW - Dual Ground/Water Speed
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vbw_dual_groundwater_speed>
+///
+/// ```text
+///         1   2   3 4   5   6
+///         |   |   | |   |   |
+///  $--VBW,x.x,x.x,A,x.x,x.x,A*hh<CR><LF>
+/// ```
+///
+/// NMEA 4.11:
+///
+/// ```text
+///         1   2   3 4   5   6 7   8 9   10
+///         |   |   | |   |   | |   | |   |
+///  $--VBW,x.x,x.x,A,x.x,x.x,A,x.x,A,x.x,A*hh<CR><LF>
+/// ```
+///
+/// Speeds are in knots. Negative longitudinal speeds are astern, negative transverse
+/// speeds are to port.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct VBW {
+    /// Longitudinal water speed in knots
+    pub water_speed_longitudinal: Option<f32>,
+    /// Transverse water speed in knots
+    pub water_speed_transverse: Option<f32>,
+    /// Status of the water speed data
+    pub water_status: Status,
+    /// Longitudinal ground speed in knots
+    pub ground_speed_longitudinal: Option<f32>,
+    /// Transverse ground speed in knots
+    pub ground_speed_transverse: Option<f32>,
+    /// Status of the ground speed data
+    pub ground_status: Status,
+    #[cfg(feature = "nmea-v4-11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    /// Stern transverse water speed in knots
+    pub stern_water_speed_transverse: Option<f32>,
+    #[cfg(feature = "nmea-v4-11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    /// Status of the stern water speed data
+    pub stern_water_status: Option<Status>,
+    #[cfg(feature = "nmea-v4-11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    /// Stern transverse ground speed in knots
+    pub stern_ground_speed_transverse: Option<f32>,
+    #[cfg(feature = "nmea-v4-11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    /// Status of the stern ground speed data
+    pub stern_ground_status: Option<Status>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vbw_parsing() {
+        #[cfg(not(feature = "nmea-v4-11"))]
+        let input = "11.0,02.0,A,08.0,-01.0,V";
+        #[cfg(feature = "nmea-v4-11")]
+        let input = "11.0,02.0,A,08.0,-01.0,V,,,,";
+
+        let result: IResult<_, _> = VBW::parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                VBW {
+                    water_speed_longitudinal: Some(11.0),
+                    water_speed_transverse: Some(2.0),
+                    water_status: Status::Valid,
+                    ground_speed_longitudinal: Some(8.0),
+                    ground_speed_transverse: Some(-1.0),
+                    ground_status: Status::Invalid,
+                    #[cfg(feature = "nmea-v4-11")]
+                    stern_water_speed_transverse: None,
+                    #[cfg(feature = "nmea-v4-11")]
+                    stern_water_status: None,
+                    #[cfg(feature = "nmea-v4-11")]
+                    stern_ground_speed_transverse: None,
+                    #[cfg(feature = "nmea-v4-11")]
+                    stern_ground_status: None,
+                }
+            ))
+        );
+
+        #[cfg(not(feature = "nmea-v4-11"))]
+        let input = ",,V,,,V";
+        #[cfg(feature = "nmea-v4-11")]
+        let input = ",,V,,,V,,,,";
+
+        let result: IResult<_, _> = VBW::parse(input);
+        assert_eq!(result, Ok(("", VBW::default())));
+
+        let result: IResult<_, VBW> = VBW::parse("11.0,02.0,,08.0,01.0,A");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "nmea-v4-11")]
+    #[test]
+    fn test_vbw_parsing_v4_11() {
+        let result: IResult<_, _> = VBW::parse("11.0,02.0,A,08.0,01.0,A,0.5,A,0.4,V");
+        let (_, vbw) = result.unwrap();
+        assert_eq!(vbw.stern_water_speed_transverse, Some(0.5));
+        assert_eq!(vbw.stern_water_status, Some(Status::Valid));
+        assert_eq!(vbw.stern_ground_speed_transverse, Some(0.4));
+        assert_eq!(vbw.stern_ground_status, Some(Status::Invalid));
+
+        let result: IResult<_, VBW> = VBW::parse("11.0,02.0,A,08.0,01.0,A");
+        assert!(result.is_err());
+    }
+}
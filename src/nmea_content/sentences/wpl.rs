@@ -0,0 +1,51 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaParse,
+    nmea_content::{Location, WaypointId, parse::location},
+};
+
+/// WPL - Waypoint Location
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_wpl_waypoint_location>
+///
+/// ```text
+///         1       2 3        4 5
+///         |       | |        | |
+///  $--WPL,llll.ll,a,yyyyy.yy,a,c--c*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+pub struct WPL {
+    #[nmea(parser(location))]
+    /// Location (latitude and longitude) of the waypoint
+    pub location: Option<Location>,
+    /// Waypoint identifier
+    pub waypoint_id: WaypointId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_wpl_parsing() {
+        let result: IResult<_, _> = WPL::parse("4917.16,N,12310.64,W,003");
+        let (rest, wpl) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(wpl.waypoint_id, "003");
+
+        let location = wpl.location.unwrap();
+        assert!((location.latitude - (49.0 + 17.16 / 60.0)).abs() < 1e-9);
+        assert!((location.longitude + (123.0 + 10.64 / 60.0)).abs() < 1e-9);
+
+        let result: IResult<_, _> = WPL::parse(",,,,003");
+        assert_eq!(result.unwrap().1.location, None);
+
+        let result: IResult<_, WPL> = WPL::parse("4917.16,N,12310.64,W,");
+        assert!(result.is_err());
+    }
+}
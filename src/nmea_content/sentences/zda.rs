@@ -1,17 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use nom::{
-    AsChar, Compare, Input, Parser,
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{char, one_of},
-    combinator::{opt, value},
-    error::ParseError,
+use crate::{
+    self as nmea0183_parser, NmeaParse,
+    nmea_content::parse::{date_full_year, utc_offset},
 };
 
-use crate::{self as nmea0183_parser, IResult, NmeaParse};
-
 /// ZDA - Time & Date - UTC, day, month, year and local time zone
 ///
 /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_zda_time_date_utc_day_month_year_and_local_time_zone>
@@ -57,66 +51,61 @@ impl From<ZDA> for Option<time::OffsetDateTime> {
     }
 }
 
-fn date_full_year<I, E>(i: I) -> IResult<I, Option<time::Date>, E>
-where
-    I: Input,
-    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
-    <I as Input>::Item: AsChar,
-    E: ParseError<I>,
-{
-    alt((value(None, tag(",,")), move |i: I| {
-        let (i, (day, month, year)) = (
-            u8::parse,
-            u8::parse_preceded(char(',')),
-            u16::parse_preceded(char(',')),
-        )
-            .parse(i)?;
-
-        let month = month
-            .try_into()
-            .or(Err(nom::Err::Error(nom::error::make_error(
-                i.clone(),
-                nom::error::ErrorKind::Verify,
-            ))))?;
-
-        let date =
-            time::Date::from_calendar_date(year as i32, month, day).or(Err(nom::Err::Error(
-                nom::error::make_error(i.clone(), nom::error::ErrorKind::Verify),
-            )))?;
-
-        Ok((i, Some(date)))
-    }))
-    .parse(i)
-}
+impl ZDA {
+    /// Returns the UTC date and time of the sentence as a Unix timestamp, in seconds.
+    ///
+    /// The local zone description is not needed, as the time and date fields are always
+    /// in UTC. Returns [`None`] if either the time or the date is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, NmeaParse, nmea_content::ZDA};
+    ///
+    /// let result: IResult<_, _> = ZDA::parse("132502.00,11,07,2025,+03,00");
+    /// let (_, zda) = result.unwrap();
+    /// assert_eq!(zda.as_unix_timestamp(), Some(1_752_240_302));
+    /// ```
+    pub fn as_unix_timestamp(&self) -> Option<i64> {
+        self.utc_date_time()
+            .map(time::OffsetDateTime::unix_timestamp)
+    }
 
-fn utc_offset<I, E>(i: I) -> IResult<I, Option<time::UtcOffset>, E>
-where
-    I: Input,
-    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
-    <I as Input>::Item: AsChar,
-    E: ParseError<I>,
-{
-    alt((value(None, char(',')), move |i: I| {
-        let (i, (sign, hours, minutes)) =
-            (opt(one_of("+-")), i8::parse, i8::parse_preceded(char(','))).parse(i)?;
-        let (hours, minutes) = match sign {
-            Some('-') => (-hours, -minutes),
-            _ => (hours, minutes),
-        };
-
-        let time = time::UtcOffset::from_hms(hours, minutes, 0).or(Err(nom::Err::Error(
-            nom::error::make_error(i.clone(), nom::error::ErrorKind::Verify),
-        )))?;
-
-        Ok((i, Some(time)))
-    }))
-    .parse(i)
+    /// Returns the skew of a system clock relative to the time of the sentence.
+    ///
+    /// The result is positive when `system_now` is ahead of the time received from the GNSS
+    /// receiver, and is meant to discipline the system clock. Returns [`None`] if either
+    /// the time or the date is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, NmeaParse, nmea_content::ZDA};
+    /// use time::{Date, Duration, Month};
+    ///
+    /// let result: IResult<_, _> = ZDA::parse("132502.00,11,07,2025,,");
+    /// let (_, zda) = result.unwrap();
+    ///
+    /// let now = Date::from_calendar_date(2025, Month::July, 11)
+    ///     .unwrap()
+    ///     .with_hms_milli(13, 25, 3, 500)
+    ///     .unwrap()
+    ///     .assume_utc();
+    /// assert_eq!(zda.offset_from(now), Some(Duration::milliseconds(1500)));
+    /// ```
+    pub fn offset_from(&self, system_now: time::OffsetDateTime) -> Option<time::Duration> {
+        self.utc_date_time().map(|date_time| system_now - date_time)
+    }
+
+    fn utc_date_time(&self) -> Option<time::OffsetDateTime> {
+        Some(time::PrimitiveDateTime::new(self.date?, self.time?).assume_utc())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::IResult;
+    use crate::{Error, IResult};
 
     #[test]
     fn test_zda_parsing() {
@@ -150,4 +139,43 @@ mod tests {
             assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
         }
     }
+
+    #[test]
+    fn test_zda_invalid_field() {
+        let result: IResult<_, _> = ZDA::parse("132502.00,11,13,2025,,");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::InvalidField("11,13,2025")))
+        );
+
+        let result: IResult<_, _> = ZDA::parse("132502.00,11,07,2025,+30,00");
+        assert_eq!(result, Err(nom::Err::Error(Error::InvalidField("+30,00"))));
+    }
+
+    #[test]
+    fn test_zda_clock_sync() {
+        let result: IResult<_, _> = ZDA::parse("132502.25,11,07,2025,-03,30");
+        let (_, zda) = result.unwrap();
+        // The time and date are in UTC, regardless of the local zone description
+        assert_eq!(zda.as_unix_timestamp(), Some(1_752_240_302));
+
+        let date = time::Date::from_calendar_date(2025, time::Month::July, 11).unwrap();
+
+        let now = date.with_hms(13, 25, 0).unwrap().assume_utc();
+        assert_eq!(
+            zda.offset_from(now),
+            Some(time::Duration::milliseconds(-2250))
+        );
+
+        let now = date
+            .with_hms_milli(16, 25, 3, 250)
+            .unwrap()
+            .assume_offset(time::UtcOffset::from_hms(3, 0, 0).unwrap());
+        assert_eq!(zda.offset_from(now), Some(time::Duration::seconds(1)));
+
+        let result: IResult<_, _> = ZDA::parse("132502.00,,,,,");
+        let (_, zda) = result.unwrap();
+        assert_eq!(zda.as_unix_timestamp(), None);
+        assert_eq!(zda.offset_from(now), None);
+    }
 }
@@ -0,0 +1,185 @@
+//! Internally-tagged serde representation of [`NmeaSentence`].
+//!
+//! By default, [`NmeaSentence`] is serialized as an externally-tagged enum, i.e.
+//! `{"GGA": {...}}`. This module serializes it with the sentence type stored in a
+//! `type` field next to the sentence fields instead, i.e. `{"type": "GGA", ...}`,
+//! which is easier to describe with JSON schemas.
+//!
+//! Use it with the `#[serde(with = "...")]` field attribute:
+//!
+//! ```rust
+//! use nmea0183_parser::{IResult, NmeaParse, nmea_content::NmeaSentence};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "nmea0183_parser::nmea_content::tagged")]
+//!     sentence: NmeaSentence,
+//! }
+//!
+//! let result: IResult<_, _> = NmeaSentence::parse("GPDBT,10.0,f,3.0,M,1.6,F");
+//! let record = Record {
+//!     sentence: result.unwrap().1,
+//! };
+//!
+//! let json = serde_json::to_string(&record).unwrap();
+//! assert!(json.starts_with(r#"{"sentence":{"type":"DBT","#));
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::nmea_content::{
+    ALM, BWC, BWR, DBT, DPT, GGA, GLL, GSA, GSV, NmeaSentence, Proprietary, RMC, RTE, VBW, VTG,
+    WPL, ZDA,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum TaggedRef<'a> {
+    ALM(&'a ALM),
+    BWC(&'a BWC),
+    BWR(&'a BWR),
+    DBT(&'a DBT),
+    DPT(&'a DPT),
+    GGA(&'a GGA),
+    GLL(&'a GLL),
+    GSA(&'a GSA),
+    GSV(&'a GSV),
+    RMC(&'a RMC),
+    RTE(&'a RTE),
+    VBW(&'a VBW),
+    VTG(&'a VTG),
+    WPL(&'a WPL),
+    ZDA(&'a ZDA),
+    Proprietary(&'a Proprietary),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum Tagged {
+    ALM(ALM),
+    BWC(BWC),
+    BWR(BWR),
+    DBT(DBT),
+    DPT(DPT),
+    GGA(GGA),
+    GLL(GLL),
+    GSA(GSA),
+    GSV(GSV),
+    RMC(RMC),
+    RTE(RTE),
+    VBW(VBW),
+    VTG(VTG),
+    WPL(WPL),
+    ZDA(ZDA),
+    Proprietary(Proprietary),
+}
+
+/// Serializes the sentence as `{"type": "<sentence type>", <sentence fields>...}`
+pub fn serialize<S>(sentence: &NmeaSentence, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match sentence {
+        NmeaSentence::ALM(alm) => TaggedRef::ALM(alm),
+        NmeaSentence::BWC(bwc) => TaggedRef::BWC(bwc),
+        NmeaSentence::BWR(bwr) => TaggedRef::BWR(bwr),
+        NmeaSentence::DBT(dbt) => TaggedRef::DBT(dbt),
+        NmeaSentence::DPT(dpt) => TaggedRef::DPT(dpt),
+        NmeaSentence::GGA(gga) => TaggedRef::GGA(gga),
+        NmeaSentence::GLL(gll) => TaggedRef::GLL(gll),
+        NmeaSentence::GSA(gsa) => TaggedRef::GSA(gsa),
+        NmeaSentence::GSV(gsv) => TaggedRef::GSV(gsv),
+        NmeaSentence::RMC(rmc) => TaggedRef::RMC(rmc),
+        NmeaSentence::RTE(rte) => TaggedRef::RTE(rte),
+        NmeaSentence::VBW(vbw) => TaggedRef::VBW(vbw),
+        NmeaSentence::VTG(vtg) => TaggedRef::VTG(vtg),
+        NmeaSentence::WPL(wpl) => TaggedRef::WPL(wpl),
+        NmeaSentence::ZDA(zda) => TaggedRef::ZDA(zda),
+        NmeaSentence::Proprietary(proprietary) => TaggedRef::Proprietary(proprietary),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a sentence serialized with [`serialize`]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NmeaSentence, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Tagged::deserialize(deserializer)? {
+        Tagged::ALM(alm) => NmeaSentence::ALM(alm),
+        Tagged::BWC(bwc) => NmeaSentence::BWC(bwc),
+        Tagged::BWR(bwr) => NmeaSentence::BWR(bwr),
+        Tagged::DBT(dbt) => NmeaSentence::DBT(dbt),
+        Tagged::DPT(dpt) => NmeaSentence::DPT(dpt),
+        Tagged::GGA(gga) => NmeaSentence::GGA(gga),
+        Tagged::GLL(gll) => NmeaSentence::GLL(gll),
+        Tagged::GSA(gsa) => NmeaSentence::GSA(gsa),
+        Tagged::GSV(gsv) => NmeaSentence::GSV(gsv),
+        Tagged::RMC(rmc) => NmeaSentence::RMC(rmc),
+        Tagged::RTE(rte) => NmeaSentence::RTE(rte),
+        Tagged::VBW(vbw) => NmeaSentence::VBW(vbw),
+        Tagged::VTG(vtg) => NmeaSentence::VTG(vtg),
+        Tagged::WPL(wpl) => NmeaSentence::WPL(wpl),
+        Tagged::ZDA(zda) => NmeaSentence::ZDA(zda),
+        Tagged::Proprietary(proprietary) => NmeaSentence::Proprietary(proprietary),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IResult, NmeaParse};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        sentence: NmeaSentence,
+    }
+
+    #[test]
+    fn test_tagged_round_trip() {
+        let cases = [
+            "GPDBT,10.0,f,3.0,M,1.6,F",
+            "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+            // The signal ID field is only present from NMEA 4.11
+            #[cfg(not(feature = "nmea-v4-11"))]
+            "GPGSV,1,1,02,05,45,120,38,07,10,010,20",
+            #[cfg(feature = "nmea-v4-11")]
+            "GPGSV,1,1,02,05,45,120,38,07,10,010,20,",
+            "GPZDA,123456.78,29,02,2024,03,00",
+        ];
+
+        for input in cases {
+            let result: IResult<_, _> = NmeaSentence::parse(input);
+            let record = Record {
+                sentence: result.unwrap().1,
+            };
+
+            let json = serde_json::to_value(&record).unwrap();
+            let sentence_type = format!("{:?}", record.sentence.sentence_type());
+            assert_eq!(json["sentence"]["type"], sentence_type, "{input:?}");
+
+            let decoded: Record = serde_json::from_value(json).unwrap();
+            assert_eq!(decoded, record, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_tagged_format() {
+        let result: IResult<_, _> = NmeaSentence::parse("GPDBT,10.0,f,3.0,M,1.6,F");
+        let record = Record {
+            sentence: result.unwrap().1,
+        };
+
+        let json = serde_json::to_value(&record).unwrap();
+        let sentence = json["sentence"].as_object().unwrap();
+        assert_eq!(sentence["type"], "DBT");
+        assert!(sentence.contains_key("waterDepth"), "{sentence:?}");
+
+        let json = serde_json::json!({"sentence": {"type": "UNK"}});
+        assert!(serde_json::from_value::<Record>(json).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use crate::nmea_content::{GSV, Satellite};
+
+/// Latest known state of a tracked satellite
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedSatellite {
+    /// Satellite information from the latest [`GSV`] sentence reporting it
+    pub satellite: Satellite,
+    /// When the satellite was last reported
+    pub last_seen: Instant,
+}
+
+/// Stateful view of the satellites in view, built from [`GSV`] sentences
+///
+/// The tracker keeps the latest [`Satellite`] information for each PRN, along with
+/// the time it was last reported. Satellites that have not been reported for longer
+/// than the configured maximum age are evicted.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use nmea0183_parser::{
+///     IResult, NmeaParse,
+///     nmea_content::{GSV, SatelliteTracker},
+/// };
+///
+/// let mut tracker = SatelliteTracker::new(Duration::from_secs(5));
+///
+/// let result: IResult<_, _> = GSV::parse("1,1,02,05,45,120,38,07,10,010,20,");
+/// tracker.ingest(&result.unwrap().1, Instant::now());
+///
+/// assert_eq!(tracker.len(), 2);
+/// assert_eq!(tracker.get(5).unwrap().satellite.snr, Some(38));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SatelliteTracker {
+    max_age: Duration,
+    satellites: BTreeMap<u8, TrackedSatellite>,
+}
+
+impl SatelliteTracker {
+    /// Creates an empty tracker evicting satellites not seen for longer than `max_age`
+    pub fn new(max_age: Duration) -> Self {
+        SatelliteTracker {
+            max_age,
+            satellites: BTreeMap::new(),
+        }
+    }
+
+    /// Records the satellites of a [`GSV`] sentence as seen at `now`,
+    /// then evicts the satellites that are too old
+    pub fn ingest(&mut self, gsv: &GSV, now: Instant) {
+        for satellite in &gsv.satellites {
+            self.satellites.insert(
+                satellite.prn,
+                TrackedSatellite {
+                    satellite: satellite.clone(),
+                    last_seen: now,
+                },
+            );
+        }
+
+        self.evict(now);
+    }
+
+    /// Removes the satellites not seen for longer than the maximum age at `now`
+    pub fn evict(&mut self, now: Instant) {
+        let max_age = self.max_age;
+        self.satellites
+            .retain(|_, tracked| now.saturating_duration_since(tracked.last_seen) <= max_age);
+    }
+
+    /// Returns the tracked satellite with the given PRN
+    pub fn get(&self, prn: u8) -> Option<&TrackedSatellite> {
+        self.satellites.get(&prn)
+    }
+
+    /// Returns an iterator over the tracked satellites, ordered by PRN
+    pub fn iter(&self) -> impl Iterator<Item = &TrackedSatellite> {
+        self.satellites.values()
+    }
+
+    /// Returns the number of tracked satellites
+    pub fn len(&self) -> usize {
+        self.satellites.len()
+    }
+
+    /// Returns `true` if no satellite is tracked
+    pub fn is_empty(&self) -> bool {
+        self.satellites.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IResult, NmeaParse};
+
+    fn gsv(input: &str) -> GSV {
+        let result: IResult<_, _> = GSV::parse(input);
+        result.unwrap().1
+    }
+
+    #[test]
+    fn test_satellite_tracker() {
+        let mut tracker = SatelliteTracker::new(Duration::from_secs(5));
+        let start = Instant::now();
+
+        // First group: PRNs 1 to 5
+        tracker.ingest(
+            &gsv("2,1,05,01,60,150,45,02,30,090,30,03,70,270,50,04,10,010,20,"),
+            start,
+        );
+        tracker.ingest(&gsv("2,2,05,05,45,120,38,"), start);
+        assert_eq!(tracker.len(), 5);
+        assert!(tracker.iter().map(|t| t.satellite.prn).eq(1..=5));
+
+        // Second group, 3s later: PRN 3 is not reported, PRN 1 has a new SNR
+        let second = start + Duration::from_secs(3);
+        tracker.ingest(
+            &gsv("2,1,04,01,61,150,42,02,30,090,30,04,10,010,20,"),
+            second,
+        );
+        tracker.ingest(&gsv("2,2,04,05,45,120,38,"), second);
+        assert_eq!(tracker.len(), 5);
+        assert_eq!(tracker.get(1).unwrap().satellite.snr, Some(42));
+        assert_eq!(tracker.get(1).unwrap().last_seen, second);
+        assert_eq!(tracker.get(3).unwrap().last_seen, start);
+
+        // PRN 3 is dropped once it is older than the maximum age
+        tracker.evict(start + Duration::from_secs(6));
+        assert_eq!(tracker.len(), 4);
+        assert!(tracker.get(3).is_none());
+        assert!(tracker.get(1).is_some());
+
+        tracker.evict(second + Duration::from_secs(6));
+        assert!(tracker.is_empty());
+    }
+}
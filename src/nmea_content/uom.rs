@@ -0,0 +1,142 @@
+use ::uom::si::{
+    f32::{Length, Velocity},
+    length::meter,
+    velocity::knot,
+};
+
+use crate::nmea_content::{DBT, DPT, GGA, RMC, VTG};
+
+fn meters(value: Option<f32>) -> Option<Length> {
+    value.map(Length::new::<meter>)
+}
+
+fn knots(value: Option<f32>) -> Option<Velocity> {
+    value.map(Velocity::new::<knot>)
+}
+
+impl DBT {
+    /// Returns the water depth as a typed length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, NmeaParse, nmea_content::DBT};
+    /// use uom::si::length::foot;
+    ///
+    /// let result: IResult<_, _> = DBT::parse("12.3,f,3.75,M,2.05,F");
+    /// let (_, dbt) = result.unwrap();
+    ///
+    /// let depth = dbt.water_depth_length().unwrap();
+    /// assert!((depth.get::<foot>() - 12.3).abs() < 0.01);
+    /// ```
+    pub fn water_depth_length(&self) -> Option<Length> {
+        meters(self.water_depth)
+    }
+}
+
+impl DPT {
+    /// Returns the water depth relative to the transducer as a typed length.
+    pub fn water_depth_length(&self) -> Option<Length> {
+        meters(self.water_depth)
+    }
+
+    /// Returns the offset from the transducer as a typed length.
+    pub fn offset_from_transducer_length(&self) -> Option<Length> {
+        meters(self.offset_from_transducer)
+    }
+
+    /// Returns the maximum range scale as a typed length.
+    #[cfg(feature = "nmea-v3-0")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v3-0")))]
+    pub fn max_range_scale_length(&self) -> Option<Length> {
+        meters(self.max_range_scale)
+    }
+}
+
+impl GGA {
+    /// Returns the altitude above mean sea level as a typed length.
+    pub fn altitude_length(&self) -> Option<Length> {
+        meters(self.altitude)
+    }
+
+    /// Returns the geoidal separation as a typed length.
+    pub fn geoidal_separation_length(&self) -> Option<Length> {
+        meters(self.geoidal_separation)
+    }
+}
+
+impl RMC {
+    /// Returns the speed over ground as a typed velocity.
+    pub fn speed_over_ground_velocity(&self) -> Option<Velocity> {
+        knots(self.speed_over_ground)
+    }
+}
+
+impl VTG {
+    /// Returns the speed over ground as a typed velocity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::VTG;
+    /// use uom::si::velocity::kilometer_per_hour;
+    ///
+    /// let vtg = VTG {
+    ///     speed_over_ground: Some(10.0),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let speed = vtg.speed_over_ground_velocity().unwrap();
+    /// assert!((speed.get::<kilometer_per_hour>() - 18.52).abs() < 1e-3);
+    /// ```
+    pub fn speed_over_ground_velocity(&self) -> Option<Velocity> {
+        knots(self.speed_over_ground)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::uom::si::{length::foot, velocity::meter_per_second};
+
+    use super::*;
+
+    #[test]
+    fn test_lengths() {
+        let gga = GGA {
+            altitude: Some(545.4),
+            geoidal_separation: None,
+            ..Default::default()
+        };
+        assert_eq!(gga.altitude_length(), Some(Length::new::<meter>(545.4)));
+        assert_eq!(gga.geoidal_separation_length(), None);
+
+        let dbt = DBT {
+            water_depth: Some(3.048),
+        };
+        let depth = dbt.water_depth_length().unwrap();
+        assert!((depth.get::<foot>() - 10.0).abs() < 1e-4);
+
+        let dpt = DPT {
+            water_depth: Some(12.5),
+            offset_from_transducer: Some(-1.5),
+            ..Default::default()
+        };
+        assert_eq!(dpt.water_depth_length(), Some(Length::new::<meter>(12.5)));
+        assert_eq!(
+            dpt.offset_from_transducer_length(),
+            Some(Length::new::<meter>(-1.5))
+        );
+    }
+
+    #[test]
+    fn test_velocities() {
+        let rmc = RMC {
+            speed_over_ground: Some(1.0),
+            ..Default::default()
+        };
+        let speed = rmc.speed_over_ground_velocity().unwrap();
+        assert!((speed.get::<meter_per_second>() - 1852.0 / 3600.0).abs() < 1e-6);
+
+        assert_eq!(VTG::default().speed_over_ground_velocity(), None);
+    }
+}
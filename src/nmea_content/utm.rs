@@ -0,0 +1,258 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::nmea_content::Location;
+
+/// WGS-84 semi-major axis in meters
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS-84 flattening
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// UTM scale factor on the central meridian
+const UTM_K0: f64 = 0.9996;
+/// False easting in meters
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+/// False northing in meters, used in the southern hemisphere
+const UTM_FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// MGRS latitude bands, 8° each from 80°S (band `X` extends to 84°N)
+const MGRS_LATITUDE_BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+/// MGRS 100km column letters, by zone set
+const MGRS_COLUMN_LETTERS: [&[u8]; 3] = [b"STUVWXYZ", b"ABCDEFGH", b"JKLMNPQR"];
+/// MGRS 100km row letters
+const MGRS_ROW_LETTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+
+/// Universal Transverse Mercator (UTM) coordinate on the WGS-84 ellipsoid
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UtmCoord {
+    /// UTM zone number (1-60)
+    pub zone: u8,
+    /// MGRS latitude band letter (`C`-`X`, excluding `I` and `O`)
+    pub band: char,
+    /// Whether the coordinate is in the northern hemisphere
+    pub north: bool,
+    /// Easting in meters, including the 500km false easting
+    pub easting: f64,
+    /// Northing in meters, including the 10000km false northing in the southern hemisphere
+    pub northing: f64,
+}
+
+impl Location {
+    /// Converts the location to a UTM coordinate using the WGS-84 ellipsoid.
+    ///
+    /// The projection uses the Krüger series, accurate to well below a millimeter
+    /// within the UTM zones. The Norway and Svalbard zone exceptions are applied.
+    ///
+    /// Returns [`None`] if the latitude is outside the UTM range (80°S to 84°N),
+    /// where the Universal Polar Stereographic projection should be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::Location;
+    ///
+    /// let eiffel_tower = Location {
+    ///     latitude: 48.8582,
+    ///     longitude: 2.2945,
+    /// };
+    ///
+    /// let utm = eiffel_tower.to_utm().unwrap();
+    /// assert_eq!((utm.zone, utm.band, utm.north), (31, 'U', true));
+    /// assert!((utm.easting - 448_251.8).abs() < 1.0);
+    /// assert!((utm.northing - 5_411_932.7).abs() < 1.0);
+    /// ```
+    pub fn to_utm(&self) -> Option<UtmCoord> {
+        let (latitude, longitude) = (self.latitude, self.longitude);
+        if !(-80.0..=84.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return None;
+        }
+
+        let zone = utm_zone(latitude, longitude);
+        let band = MGRS_LATITUDE_BANDS[(((latitude + 80.0) / 8.0) as usize).min(19)] as char;
+        let central_meridian = (zone as f64 - 1.0) * 6.0 - 180.0 + 3.0;
+
+        let n = WGS84_F / (2.0 - WGS84_F);
+        let (n2, n3) = (n * n, n * n * n);
+        let rectifying_radius = WGS84_A / (1.0 + n) * (1.0 + n2 / 4.0 + n2 * n2 / 64.0);
+        let alpha = [
+            n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0,
+            13.0 * n2 / 48.0 - 3.0 * n3 / 5.0,
+            61.0 * n3 / 240.0,
+        ];
+
+        let phi = latitude.to_radians();
+        let lambda = (longitude - central_meridian).to_radians();
+
+        let e = 2.0 * n.sqrt() / (1.0 + n);
+        let t = (phi.sin().atanh() - e * (e * phi.sin()).atanh()).sinh();
+        let xi = t.atan2(lambda.cos());
+        let eta = (lambda.sin() / (1.0 + t * t).sqrt()).atanh();
+
+        let (mut x, mut y) = (eta, xi);
+        for (j, alpha) in alpha.iter().enumerate() {
+            let j = 2.0 * (j + 1) as f64;
+            x += alpha * (j * xi).cos() * (j * eta).sinh();
+            y += alpha * (j * xi).sin() * (j * eta).cosh();
+        }
+
+        let north = latitude >= 0.0;
+        let easting = UTM_FALSE_EASTING + UTM_K0 * rectifying_radius * x;
+        let northing =
+            UTM_K0 * rectifying_radius * y + if north { 0.0 } else { UTM_FALSE_NORTHING };
+
+        Some(UtmCoord {
+            zone,
+            band,
+            north,
+            easting,
+            northing,
+        })
+    }
+
+    /// Converts the location to a Military Grid Reference System (MGRS) string
+    /// with a precision of 1 meter, e.g. `31U DQ 48251 11932`.
+    ///
+    /// Returns [`None`] if the latitude is outside the UTM range (80°S to 84°N).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::nmea_content::Location;
+    ///
+    /// let eiffel_tower = Location {
+    ///     latitude: 48.8582,
+    ///     longitude: 2.2945,
+    /// };
+    ///
+    /// assert_eq!(eiffel_tower.to_mgrs().unwrap(), "31U DQ 48251 11932");
+    /// ```
+    pub fn to_mgrs(&self) -> Option<String> {
+        let utm = self.to_utm()?;
+
+        let column = utm.easting as u32 / 100_000;
+        let row = utm.northing as u32 / 100_000;
+        let column_letter =
+            MGRS_COLUMN_LETTERS[(utm.zone % 3) as usize][(column as usize).clamp(1, 8) - 1] as char;
+        let row_offset = if utm.zone % 2 == 0 { 5 } else { 0 };
+        let row_letter = MGRS_ROW_LETTERS[(row as usize + row_offset) % 20] as char;
+
+        let easting = utm.easting as u32 % 100_000;
+        let northing = utm.northing as u32 % 100_000;
+
+        Some(format!(
+            "{}{} {column_letter}{row_letter} {easting:05} {northing:05}",
+            utm.zone, utm.band
+        ))
+    }
+}
+
+/// Returns the UTM zone of the given location, including the Norway and Svalbard exceptions
+fn utm_zone(latitude: f64, longitude: f64) -> u8 {
+    let zone = (((longitude + 180.0) / 6.0) as u8 % 60) + 1;
+
+    if (56.0..64.0).contains(&latitude) && (3.0..12.0).contains(&longitude) {
+        return 32;
+    }
+
+    if (72.0..=84.0).contains(&latitude) && (0.0..42.0).contains(&longitude) {
+        return match longitude {
+            lon if lon < 9.0 => 31,
+            lon if lon < 21.0 => 33,
+            lon if lon < 33.0 => 35,
+            _ => 37,
+        };
+    }
+
+    zone
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(latitude: f64, longitude: f64) -> Location {
+        Location {
+            latitude,
+            longitude,
+        }
+    }
+
+    fn assert_utm(utm: &UtmCoord, zone: u8, band: char, easting: f64, northing: f64) {
+        assert_eq!((utm.zone, utm.band), (zone, band), "{utm:?}");
+        assert!((utm.easting - easting).abs() < 1e-3, "{utm:?}");
+        assert!((utm.northing - northing).abs() < 1e-3, "{utm:?}");
+    }
+
+    #[test]
+    fn test_to_utm_central_meridian() {
+        let utm = location(0.0, 3.0).to_utm().unwrap();
+        assert_utm(&utm, 31, 'N', 500_000.0, 0.0);
+        assert!(utm.north);
+
+        // Meridian arc length from the equator to 45°N is 4984944.378m
+        let utm = location(45.0, 3.0).to_utm().unwrap();
+        assert_utm(&utm, 31, 'T', 500_000.0, 0.9996 * 4_984_944.378);
+
+        let utm = location(-45.0, -177.0).to_utm().unwrap();
+        assert_utm(
+            &utm,
+            1,
+            'G',
+            500_000.0,
+            10_000_000.0 - 0.9996 * 4_984_944.378,
+        );
+        assert!(!utm.north);
+    }
+
+    #[test]
+    fn test_to_utm_symmetry() {
+        let east = location(37.5, 15.5).to_utm().unwrap();
+        let west = location(37.5, 14.5).to_utm().unwrap();
+        assert_eq!(east.zone, 33);
+        assert!((east.easting - 500_000.0 - (500_000.0 - west.easting)).abs() < 1e-6);
+        assert!((east.northing - west.northing).abs() < 1e-6);
+
+        let south = location(-37.5, 15.5).to_utm().unwrap();
+        assert!((south.northing - (10_000_000.0 - east.northing)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_to_utm_reference() {
+        // Eiffel Tower
+        let utm = location(48.8582, 2.2945).to_utm().unwrap();
+        assert_eq!((utm.zone, utm.band), (31, 'U'));
+        assert!((utm.easting - 448_251.8).abs() < 1.0, "{utm:?}");
+        assert!((utm.northing - 5_411_932.7).abs() < 1.0, "{utm:?}");
+    }
+
+    #[test]
+    fn test_to_utm_zone_exceptions() {
+        assert_eq!(location(60.0, 4.0).to_utm().unwrap().zone, 32);
+        assert_eq!(location(60.0, 2.0).to_utm().unwrap().zone, 31);
+        assert_eq!(location(78.0, 8.0).to_utm().unwrap().zone, 31);
+        assert_eq!(location(78.0, 10.0).to_utm().unwrap().zone, 33);
+        assert_eq!(location(78.0, 40.0).to_utm().unwrap().zone, 37);
+        assert_eq!(location(0.0, 180.0).to_utm().unwrap().zone, 1);
+        assert_eq!(location(0.0, -180.0).to_utm().unwrap().zone, 1);
+    }
+
+    #[test]
+    fn test_to_utm_out_of_range() {
+        assert!(location(84.5, 0.0).to_utm().is_none());
+        assert!(location(-80.5, 0.0).to_utm().is_none());
+        assert!(location(84.5, 0.0).to_mgrs().is_none());
+        assert_eq!(location(84.0, 0.0).to_utm().unwrap().band, 'X');
+        assert_eq!(location(-80.0, 0.0).to_utm().unwrap().band, 'C');
+    }
+
+    #[test]
+    fn test_to_mgrs() {
+        assert_eq!(
+            location(48.8582, 2.2945).to_mgrs().unwrap(),
+            "31U DQ 48251 11932"
+        );
+        assert_eq!(location(0.0, 3.0).to_mgrs().unwrap(), "31N EA 00000 00000");
+        // Even zones start the row letters at `F`
+        assert_eq!(location(0.0, 9.0).to_mgrs().unwrap(), "32N NF 00000 00000");
+    }
+}
@@ -1,12 +1,15 @@
 use nom::{
     AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    bytes::complete::{take_till, take_till1},
     character::complete::{anychar, char},
     combinator::opt,
-    error::ParseError,
-    multi::many0,
+    error::{ErrorKind, ParseError},
     sequence::preceded,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use crate::{Error, IResult};
 
 /// Trait for parsing types from NMEA 0183 sentence fields.
@@ -194,8 +197,34 @@ macro_rules! impl_ints_type {
     )*)
 }
 
+macro_rules! impl_nonzero_type {
+    ($($t:ty),*) => ($(
+        impl<I, E> NmeaParse<I, E> for core::num::NonZero<$t>
+        where
+            $t: NmeaParse<I, E>,
+            I: Input,
+            <I as Input>::Item: AsChar,
+            E: ParseError<I>,
+        {
+            fn parse(i: I) -> IResult<I, Self, E> {
+                let (rest, value) = <$t>::parse(i.clone())?;
+
+                match core::num::NonZero::new(value) {
+                    Some(value) => Ok((rest, value)),
+                    None => Err(nom::Err::Error(nom::error::make_error(
+                        i,
+                        nom::error::ErrorKind::Verify,
+                    ))),
+                }
+            }
+        }
+    )*)
+}
+
 impl_uints_type!(u8, u16, u32, u64, u128, usize);
 impl_ints_type!(i8, i16, i32, i64, i128, isize);
+impl_nonzero_type!(u8, u16, u32, u64, u128, usize);
+impl_nonzero_type!(i8, i16, i32, i64, i128, isize);
 
 macro_rules! impl_float_type {
     ($($t:ty, $p:ident),*) => ($(
@@ -227,6 +256,38 @@ where
     }
 }
 
+/// Borrows a text field up to the next comma from the input.
+///
+/// The field must not be empty, so that an empty `Option<&str>` field is [`None`].
+impl<'a, E> NmeaParse<&'a str, E> for &'a str
+where
+    E: ParseError<&'a str>,
+{
+    fn parse(i: &'a str) -> IResult<&'a str, Self, E> {
+        take_till1(|c| c == ',').parse(i)
+    }
+}
+
+impl<I, E> NmeaParse<I, E> for bool
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let (rest, flag) = <char as NmeaParse<I, E>>::parse(i.clone())?;
+
+        match flag {
+            '0' => Ok((rest, false)),
+            '1' => Ok((rest, true)),
+            _ => Err(nom::Err::Error(nom::error::make_error(
+                i,
+                nom::error::ErrorKind::Verify,
+            ))),
+        }
+    }
+}
+
 impl<T, I, E> NmeaParse<I, E> for Option<T>
 where
     T: NmeaParse<I, E>,
@@ -266,49 +327,102 @@ where
     }
 }
 
-impl<T, I, E, const N: usize> NmeaParse<I, E> for [T; N]
+/// Parses a field that never fails the whole sentence, keeping its error locally.
+///
+/// The value is parsed with `T::parse`. If it fails, or does not end at a comma or at the
+/// end of the input, the field is skipped up to the next comma and `Err(kind)` is produced
+/// instead, where `kind` is the [`ErrorKind`] of the failure ([`ErrorKind::Verify`] for
+/// trailing characters and [`Error::InvalidField`]). Unrecoverable errors,
+/// i.e. [`nom::Err::Failure`] and [`nom::Err::Incomplete`], are still propagated.
+///
+/// As `parse_preceded` is not overridden, the separator must still be present: only the
+/// field after it is recovered. An empty field is an error for most types, use
+/// `Result<Option<T>, ErrorKind>` to parse it as `Ok(None)`. The recovery skips a single
+/// field, so `T` should not span several fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, NmeaParse};
+/// use nom::{Parser, character::complete::char, error::ErrorKind};
+///
+/// let result: IResult<_, _> = Result::<u8, ErrorKind>::parse("42,1");
+/// assert_eq!(result, Ok((",1", Ok(42))));
+///
+/// let result: IResult<_, _> = Result::<u8, ErrorKind>::parse("x2,1");
+/// assert_eq!(result, Ok((",1", Err(ErrorKind::Digit))));
+///
+/// let result: IResult<_, _> = Result::<Option<u8>, ErrorKind>::parse_preceded(char(',')).parse(",,1");
+/// assert_eq!(result, Ok((",1", Ok(None))));
+/// ```
+impl<T, I, E> NmeaParse<I, E> for Result<T, ErrorKind>
 where
-    T: NmeaParse<I, E> + Default + Copy,
+    T: NmeaParse<I, nom::error::Error<I>>,
     I: Input,
     <I as Input>::Item: AsChar,
     E: ParseError<I>,
 {
     fn parse(i: I) -> IResult<I, Self, E> {
-        let mut elems = [T::default(); N];
-        let mut i = i;
+        let kind = match T::parse(i.clone()) {
+            Ok((rest, value)) => match rest.iter_elements().next() {
+                None => return Ok((rest, Ok(value))),
+                Some(c) if c.as_char() == ',' => return Ok((rest, Ok(value))),
+                Some(_) => ErrorKind::Verify,
+            },
+            Err(nom::Err::Error(error)) => error_kind(&error),
+            Err(nom::Err::Failure(error)) => return Err(nom::Err::Failure(convert_error(error))),
+            Err(nom::Err::Incomplete(needed)) => return Err(nom::Err::Incomplete(needed)),
+        };
 
-        match T::parse(i.clone()) {
-            Ok((i1, first)) => {
-                elems[0] = first;
-                i = i1;
-            }
-            Err(nom::Err::Error(_)) => {
-                return Err(nom::Err::Error(nom::error::make_error(
-                    i,
-                    nom::error::ErrorKind::Count,
-                )));
-            }
-            Err(nom::Err::Failure(e)) => return Err(nom::Err::Failure(e)),
-            Err(nom::Err::Incomplete(e)) => return Err(nom::Err::Incomplete(e)),
-        }
+        let (rest, _) = take_till(|c: <I as Input>::Item| c.as_char() == ',').parse(i)?;
+        Ok((rest, Err(kind)))
+    }
+}
 
-        for elem in &mut elems[1..] {
-            match preceded(char(','), T::parse).parse(i.clone()) {
-                Ok((i1, next)) => {
-                    *elem = next;
-                    i = i1;
-                }
-                Err(nom::Err::Error(_)) => {
-                    return Err(nom::Err::Error(nom::error::make_error(
-                        i,
-                        nom::error::ErrorKind::Count,
-                    )));
-                }
-                Err(e) => return Err(e),
-            };
+/// Returns the [`ErrorKind`] of an error, [`ErrorKind::Verify`] for invalid fields and
+/// [`ErrorKind::Fail`] for errors that are not related to a field.
+fn error_kind<I>(error: &Error<I, nom::error::Error<I>>) -> ErrorKind {
+    match error {
+        Error::ParsingError(error) => error.code,
+        Error::InvalidField(_) => ErrorKind::Verify,
+        _ => ErrorKind::Fail,
+    }
+}
+
+/// Converts an error with the default inner error into one with any inner error type.
+fn convert_error<I, E>(error: Error<I, nom::error::Error<I>>) -> Error<I, E>
+where
+    E: ParseError<I>,
+{
+    match error {
+        Error::NonAscii => Error::NonAscii,
+        Error::ChecksumMissing => Error::ChecksumMissing,
+        Error::ChecksumMismatch { expected, found } => Error::ChecksumMismatch { expected, found },
+        Error::TooLong { max_length, length } => Error::TooLong { max_length, length },
+        Error::ParsingError(error) => {
+            Error::ParsingError(E::from_error_kind(error.input, error.code))
         }
+        Error::UnrecognizedMessage(input) => Error::UnrecognizedMessage(input),
+        Error::InvalidField(input) => Error::InvalidField(input),
+        Error::Unknown => Error::Unknown,
+    }
+}
 
-        Ok((i, elems))
+impl<T, I, E, const N: usize> NmeaParse<I, E> for [T; N]
+where
+    T: NmeaParse<I, E>,
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        parse_array(i, |index, i| {
+            if index == 0 {
+                T::parse(i)
+            } else {
+                preceded(char(','), T::parse).parse(i)
+            }
+        })
     }
 
     fn parse_preceded<S>(separator: S) -> impl Parser<I, Output = Self, Error = Error<I, E>>
@@ -316,31 +430,61 @@ where
         S: Parser<I, Error = Error<I, E>>,
     {
         let mut parser = T::parse_preceded(separator);
-        move |i: I| {
-            let mut i = i;
-            let mut elems = [T::default(); N];
-
-            for elem in &mut elems {
-                match parser.parse(i.clone()) {
-                    Ok((i1, next)) => {
-                        *elem = next;
-                        i = i1;
-                    }
-                    Err(nom::Err::Error(_)) => {
-                        return Err(nom::Err::Error(nom::error::make_error(
-                            i,
-                            nom::error::ErrorKind::Count,
-                        )));
-                    }
-                    Err(e) => return Err(e),
-                };
-            }
+        move |i: I| parse_array(i, |_, i| parser.parse(i))
+    }
+}
+
+/// Parses the elements of an array in order, the parser being given the index of each
+/// element.
+///
+/// An element is missing if the input is exhausted at its position, which is reported as
+/// [`ErrorKind::Count`](nom::error::ErrorKind::Count), so that too few elements can be told
+/// apart from a malformed element, whose error is returned as-is. Elements remaining after
+/// the `N`th one are left in the input.
+fn parse_array<T, I, E, F, const N: usize>(i: I, mut parser: F) -> IResult<I, [T; N], E>
+where
+    I: Input,
+    E: ParseError<I>,
+    F: FnMut(usize, I) -> IResult<I, T, E>,
+{
+    let mut i = i;
+    let mut error = None;
 
-            Ok((i, elems))
+    // Elements after an error are not parsed, and the array is then discarded
+    let elems: [Option<T>; N] = core::array::from_fn(|index| {
+        if error.is_some() {
+            return None;
         }
+
+        match parser(index, i.clone()) {
+            Ok((i1, elem)) => {
+                i = i1;
+                Some(elem)
+            }
+            Err(nom::Err::Error(_)) if i.input_len() == 0 => {
+                error = Some(nom::Err::Error(nom::error::make_error(
+                    i.clone(),
+                    nom::error::ErrorKind::Count,
+                )));
+                None
+            }
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }
+    });
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok((
+            i,
+            elems.map(|elem| elem.expect("all the elements are parsed")),
+        )),
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T, I, E> NmeaParse<I, E> for Vec<T>
 where
     T: NmeaParse<I, E>,
@@ -396,15 +540,285 @@ where
     where
         S: Parser<I, Error = Error<I, E>>,
     {
+        use nom::multi::many0;
+
         many0(<T>::parse_preceded(separator))
     }
 }
 
+/// Parses comma-separated elements into a `Vec<T>` until the terminator parser succeeds.
+///
+/// Unlike [`Vec<T>::parse`](NmeaParse::parse), which greedily consumes elements as long as
+/// they parse, the terminator is tried before each element following the first one, where
+/// the input starts with the preceding comma, and parsing stops as soon as it succeeds. The terminator does not consume any input, so
+/// a `Vec` field can be followed by more fields of the same type. This is the `NmeaParse`
+/// analog of [`nom::multi::many_till`].
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, parse_until};
+/// use nom::{Parser, character::complete::{char, one_of}, sequence::preceded};
+///
+/// // The list of IDs ends at the mode indicator
+/// let result: IResult<_, Vec<u8>> = parse_until(preceded(char(','), one_of("AV"))).parse("1,2,3,A");
+/// assert_eq!(result, Ok((",A", vec![1, 2, 3])));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_until<T, I, E, U>(
+    terminator: U,
+) -> impl Parser<I, Output = Vec<T>, Error = Error<I, E>>
+where
+    T: NmeaParse<I, E>,
+    I: Clone + Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    U: Parser<I, Error = Error<I, E>>,
+{
+    let mut rest = parse_preceded_until(char(','), terminator);
+    move |i: I| {
+        let (i, first) = match T::parse(i.clone()) {
+            Ok((i1, first)) => (i1, first),
+            Err(nom::Err::Error(_)) => return Ok((i, Vec::new())),
+            Err(e) => return Err(e),
+        };
+
+        let (i, mut elems) = rest.parse(i)?;
+        elems.insert(0, first);
+
+        Ok((i, elems))
+    }
+}
+
+/// Parses elements, each preceded by the separator, into a `Vec<T>` until the terminator
+/// parser succeeds.
+///
+/// This is the [`parse_preceded`](NmeaParse::parse_preceded) counterpart of [`parse_until`]:
+/// the terminator is tried before each separator, and the parsing stops without consuming
+/// it. Elements are parsed with `T::parse_preceded`, so `Option<T>` elements keep their
+/// handling of empty fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, parse_preceded_until};
+/// use nom::{Parser, bytes::complete::tag, character::complete::char};
+///
+/// let result: IResult<_, Vec<Option<u8>>> =
+///     parse_preceded_until(char(','), tag(",*")).parse(",1,,3,*");
+/// assert_eq!(result, Ok((",*", vec![Some(1), None, Some(3)])));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_preceded_until<T, I, E, S, U>(
+    separator: S,
+    terminator: U,
+) -> impl Parser<I, Output = Vec<T>, Error = Error<I, E>>
+where
+    T: NmeaParse<I, E>,
+    I: Clone + Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    S: Parser<I, Error = Error<I, E>>,
+    U: Parser<I, Error = Error<I, E>>,
+{
+    let mut element = T::parse_preceded(separator);
+    let mut terminator = terminator;
+    move |i: I| {
+        let mut elems = Vec::new();
+        let mut i = i;
+
+        loop {
+            match terminator.parse(i.clone()) {
+                Ok(_) => return Ok((i, elems)),
+                Err(nom::Err::Error(_)) => {}
+                Err(e) => return Err(e),
+            }
+
+            let len = i.input_len();
+            match element.parse(i.clone()) {
+                Ok((i1, next)) => {
+                    // infinite loop check: the parser must always consume
+                    if i1.input_len() == len {
+                        return Err(nom::Err::Error(nom::error::make_error(
+                            i,
+                            nom::error::ErrorKind::Many0,
+                        )));
+                    }
+
+                    elems.push(next);
+                    i = i1;
+                }
+                Err(nom::Err::Error(_)) => return Ok((i, elems)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{IResult, NmeaParse};
+    use alloc::{vec, vec::Vec};
+
+    use crate::{Error, IResult, NmeaParse};
     use nom::{Parser, character::complete::char};
 
+    #[test]
+    fn test_parse_bool() {
+        let result: IResult<_, _> = bool::parse("0");
+        assert_eq!(result, Ok(("", false)));
+
+        let result: IResult<_, _> = bool::parse("1");
+        assert_eq!(result, Ok(("", true)));
+
+        let result: IResult<_, _> = bool::parse("2");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("2", nom::error::ErrorKind::Verify)
+            )))
+        );
+
+        let result: IResult<_, _> = Option::<bool>::parse_preceded(char(',')).parse(",,1");
+        assert_eq!(result, Ok((",1", None)));
+
+        let result: IResult<_, _> = Vec::<Option<bool>>::parse("1,,0");
+        assert_eq!(result, Ok(("", vec![Some(true), None, Some(false)])));
+    }
+
+    #[test]
+    fn test_parse_str() {
+        let result: IResult<_, _> = <&str>::parse("W3IWI,DRIVWY");
+        assert_eq!(result, Ok((",DRIVWY", "W3IWI")));
+
+        let result: IResult<_, &str> = <&str>::parse(",DRIVWY");
+        assert!(result.is_err());
+
+        let result: IResult<_, _> = <Option<&str>>::parse_preceded(char(',')).parse(",,32CEDR");
+        assert_eq!(result, Ok((",32CEDR", None)));
+
+        let result: IResult<_, _> = Vec::<&str>::parse("W3IWI,DRIVWY,32CEDR");
+        assert_eq!(result, Ok(("", vec!["W3IWI", "DRIVWY", "32CEDR"])));
+    }
+
+    #[test]
+    fn test_parse_nonzero() {
+        use core::num::{NonZeroI16, NonZeroU8};
+
+        let result: IResult<_, _> = NonZeroU8::parse("5,");
+        assert_eq!(result, Ok((",", NonZeroU8::new(5).unwrap())));
+
+        let result: IResult<_, _> = NonZeroI16::parse("-42");
+        assert_eq!(result, Ok(("", NonZeroI16::new(-42).unwrap())));
+
+        let result: IResult<_, NonZeroU8> = NonZeroU8::parse("0,");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("0,", nom::error::ErrorKind::Verify)
+            )))
+        );
+
+        let result: IResult<_, _> = <Option<NonZeroU8>>::parse(",");
+        assert_eq!(result, Ok((",", None)));
+    }
+
+    #[test]
+    fn test_parse_array() {
+        let result: IResult<_, _> = <[u8; 3]>::parse("1,2,3,4");
+        assert_eq!(result, Ok((",4", [1, 2, 3])));
+
+        let result: IResult<_, _> = <[Option<u8>; 3]>::parse_preceded(char(',')).parse(",1,,3");
+        assert_eq!(result, Ok(("", [Some(1), None, Some(3)])));
+
+        let result: IResult<_, [u8; 3]> = <[u8; 3]>::parse("1,2");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("", nom::error::ErrorKind::Count)
+            )))
+        );
+
+        let result: IResult<_, _> = <[u8; 0]>::parse("1");
+        assert_eq!(result, Ok(("1", [])));
+    }
+
+    #[test]
+    fn test_parse_array_errors() {
+        use nom::error::ErrorKind;
+
+        // N-1 elements
+        let result: IResult<_, [u8; 3]> = <[u8; 3]>::parse("1,2");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("", ErrorKind::Count)
+            )))
+        );
+
+        let result: IResult<_, [u8; 3]> = <[u8; 3]>::parse_preceded(char(',')).parse(",1,2");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("", ErrorKind::Count)
+            )))
+        );
+
+        // Malformed element in position 2
+        let result: IResult<_, [u8; 3]> = <[u8; 3]>::parse("1,x,3");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("x,3", ErrorKind::Digit)
+            )))
+        );
+
+        let result: IResult<_, [u8; 3]> = <[u8; 3]>::parse_preceded(char(',')).parse(",1,x,3");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("x,3", ErrorKind::Digit)
+            )))
+        );
+
+        // Empty input
+        let result: IResult<_, [u8; 3]> = <[u8; 3]>::parse("");
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(Error::ParsingError(
+                nom::error::Error::new("", ErrorKind::Count)
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_result() {
+        use nom::error::ErrorKind;
+
+        let result: IResult<_, _> = Result::<u8, ErrorKind>::parse("12");
+        assert_eq!(result, Ok(("", Ok(12))));
+
+        let result: IResult<_, _> = Result::<u8, ErrorKind>::parse("300,1");
+        assert_eq!(result, Ok((",1", Err(ErrorKind::Digit))));
+
+        let result: IResult<_, _> = Result::<u8, ErrorKind>::parse("12ab,1");
+        assert_eq!(result, Ok((",1", Err(ErrorKind::Verify))));
+
+        let result: IResult<_, _> = Result::<bool, ErrorKind>::parse("x");
+        assert_eq!(result, Ok(("", Err(ErrorKind::Verify))));
+
+        let result: IResult<_, _> = Result::<u8, ErrorKind>::parse_preceded(char(',')).parse(",,1");
+        assert_eq!(result, Ok((",1", Err(ErrorKind::Digit))));
+
+        let result: IResult<_, _> =
+            Result::<Option<u8>, ErrorKind>::parse_preceded(char(',')).parse(",,1");
+        assert_eq!(result, Ok((",1", Ok(None))));
+
+        // The separator is still required
+        let result: IResult<_, Result<u8, ErrorKind>> =
+            Result::<u8, ErrorKind>::parse_preceded(char(',')).parse("1");
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_parse_vec() {
         let input = "1,2,,4";
@@ -417,4 +831,32 @@ mod tests {
         let result: IResult<_, _> = Vec::<Option<u8>>::parse_preceded(char(',')).parse(input);
         assert_eq!(result, Ok(("", expected)));
     }
+
+    #[test]
+    fn test_parse_until() {
+        use super::{parse_preceded_until, parse_until};
+        use nom::{character::complete::one_of, sequence::preceded};
+
+        // Greedy parsing swallows the trailing numeric fields
+        let result: IResult<_, _> = Vec::<u8>::parse("1,2,3,9");
+        assert_eq!(result, Ok(("", vec![1, 2, 3, 9])));
+
+        let terminator = || preceded(char(','), char('9'));
+        let result: IResult<_, Vec<u8>> = parse_until(terminator()).parse("1,2,3,9");
+        assert_eq!(result, Ok((",9", vec![1, 2, 3])));
+
+        let result: IResult<_, Vec<u8>> = parse_until(terminator()).parse("1,2,3");
+        assert_eq!(result, Ok(("", vec![1, 2, 3])));
+
+        let result: IResult<_, Vec<u8>> = parse_until(terminator()).parse("A");
+        assert_eq!(result, Ok(("A", vec![])));
+
+        let result: IResult<_, Vec<Option<u8>>> =
+            parse_preceded_until(char(','), preceded(char(','), one_of("AV"))).parse(",1,,3,A");
+        assert_eq!(result, Ok((",A", vec![Some(1), None, Some(3)])));
+
+        let result: IResult<_, Vec<Option<u8>>> =
+            parse_preceded_until(char(','), preceded(char(','), one_of("AV"))).parse(",A");
+        assert_eq!(result, Ok((",A", vec![])));
+    }
 }
@@ -0,0 +1,284 @@
+//! # Sentence Splitting
+//!
+//! This module provides helpers to split a buffer holding many concatenated
+//! NMEA 0183 sentences, e.g. read from a TCP socket, into individual sentences
+//! that can be fed one by one to the framing parser.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    ChecksumMode, IResult, LineEndingMode, Nmea0183Encoder, Nmea0183ParserBuilder, StartDelimiter,
+};
+
+/// Returns an iterator over the complete sentences of the given buffer.
+///
+/// Each sentence is yielded as a slice of the input, starting with `$` or `!` and ending
+/// with its `\r\n` or `\n` terminator (included), so it can be fed as-is to a parser
+/// built with [`LineEndingMode::CrlfOrLf`](crate::LineEndingMode::CrlfOrLf).
+///
+/// The splitting is done without allocation and without validating the sentences:
+/// - Garbage before a start delimiter is skipped, including a truncated sentence
+///   interrupted by the start of a new one
+/// - An incomplete trailing sentence (without terminator) is not yielded, see
+///   [`Sentences::remainder`] to carry it over to the next buffer
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::sentences;
+///
+/// let buffer = "garbage$GPGGA,data*6A\r\n$GPZDA,123\n$GPGSV,part";
+///
+/// let mut iter = sentences(buffer);
+/// assert_eq!(iter.next(), Some("$GPGGA,data*6A\r\n"));
+/// assert_eq!(iter.next(), Some("$GPZDA,123\n"));
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(iter.remainder(), "$GPGSV,part");
+/// ```
+pub fn sentences(input: &str) -> Sentences<'_> {
+    Sentences { rest: input }
+}
+
+/// Iterator over the complete sentences of a buffer, created by [`sentences`].
+#[derive(Debug, Clone)]
+pub struct Sentences<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Sentences<'a> {
+    /// Returns the part of the buffer that has not been yielded yet.
+    ///
+    /// Once the iterator is exhausted, this is the incomplete trailing sentence,
+    /// starting at its start delimiter, or an empty string if there is none.
+    pub fn remainder(&self) -> &'a str {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for Sentences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(start) = self.rest.find(is_start_delimiter) else {
+            self.rest = &self.rest[self.rest.len()..];
+            return None;
+        };
+        self.rest = &self.rest[start..];
+
+        let end = self.rest.find('\n')? + 1;
+        let frame = &self.rest[..end];
+        self.rest = &self.rest[end..];
+
+        // A sentence interrupted by the start of a new one is garbage
+        let start = frame.rfind(is_start_delimiter).unwrap_or(0);
+        Some(&frame[start..])
+    }
+}
+
+/// Splits a buffer into raw sentence slices, without any validation.
+///
+/// Unlike [`sentences`], every part of the buffer is yielded, including garbage and
+/// malformed or incomplete sentences, so that callers can decide what to do with them,
+/// e.g. route them or count them. Line terminators are not part of the yielded slices.
+///
+/// A slice ends at the first of:
+/// - A `\r` or `\n` line terminator
+/// - The two characters following a `*` checksum delimiter
+/// - The start of a new sentence (`$` or `!`)
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::split_sentences;
+///
+/// let buffer = "$GPGGA,data*6A$GPZDA,123\r\nnoise\n$GPGSV,part";
+///
+/// let slices: Vec<_> = split_sentences(buffer).collect();
+/// assert_eq!(slices, ["$GPGGA,data*6A", "$GPZDA,123", "noise", "$GPGSV,part"]);
+/// ```
+pub fn split_sentences(buf: &str) -> impl Iterator<Item = &str> {
+    let mut rest = buf;
+
+    core::iter::from_fn(move || {
+        rest = rest.trim_start_matches(['\r', '\n']);
+        let first = rest.chars().next()?;
+
+        let mut end = rest.find(['\r', '\n']).unwrap_or(rest.len());
+        if let Some(next) = rest[first.len_utf8()..end].find(is_start_delimiter) {
+            end = first.len_utf8() + next;
+        }
+        if let Some(asterisk) = rest[..end].find('*') {
+            let checksum = &rest[asterisk + 1..end];
+            end = asterisk
+                + 1
+                + checksum
+                    .char_indices()
+                    .nth(2)
+                    .map_or(checksum.len(), |(i, _)| i);
+        }
+
+        let (slice, tail) = rest.split_at(end);
+        rest = tail;
+        Some(slice)
+    })
+}
+
+/// Returns an iterator re-emitting the sentences of a log as canonical NMEA 0183 text.
+///
+/// The log is split with [`split_sentences`] and each slice is parsed by the framing
+/// parser, then re-encoded with its start delimiter, an uppercase checksum freshly
+/// computed over its content and a `\r\n` line ending. This is useful to clean up
+/// logs before diffing or archiving them.
+///
+/// Sentences without a checksum are accepted and get one, so a sentence truncated before
+/// its checksum cannot be told apart from a complete one. Slices that are not valid
+/// sentences, i.e. garbage, sentences with an invalid checksum or with non-ASCII content,
+/// are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::normalize;
+///
+/// let log = "noise\n$GPGGA,data*6a\n$GPZDA,123\r\n$GPGGA,data*00";
+///
+/// let sentences: Vec<_> = normalize(log).collect();
+/// assert_eq!(sentences, ["$GPGGA,data*6A\r\n", "$GPZDA,123*54\r\n"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn normalize(input: &str) -> impl Iterator<Item = String> + '_ {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .start_delimiter(StartDelimiter::Either)
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::Forbidden)
+        .build_with_meta(nom::combinator::rest);
+    let encoder = Nmea0183Encoder::new();
+
+    split_sentences(input).filter_map(move |slice| {
+        let result: IResult<_, _> = parser(slice);
+        let (_, (meta, content)) = result.ok()?;
+
+        let mut sentence = encoder.encode(content).ok()?;
+        if meta.start != '$' {
+            sentence.replace_range(..1, "!");
+        }
+        Some(sentence)
+    })
+}
+
+fn is_start_delimiter(c: char) -> bool {
+    c == '$' || c == '!'
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn test_sentences() {
+        let buffer =
+            "$GPGGA,data*6A\r\n$GPZDA,123\n!AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5F\r\n";
+
+        let frames: Vec<_> = sentences(buffer).collect();
+        assert_eq!(
+            frames,
+            [
+                "$GPGGA,data*6A\r\n",
+                "$GPZDA,123\n",
+                "!AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5F\r\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sentences_garbage() {
+        let buffer = "\0\x7fnoise\r\n$GPGGA,trunc$GPGGA,data*6A\r\nmore noise\n$GPZDA,123\r\n";
+
+        let frames: Vec<_> = sentences(buffer).collect();
+        assert_eq!(frames, ["$GPGGA,data*6A\r\n", "$GPZDA,123\r\n"]);
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        let buffer =
+            "$GPGGA,data*6A\r\n$GPZDA,123\n!AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5F\r\n";
+
+        let slices: Vec<_> = split_sentences(buffer).collect();
+        assert_eq!(
+            slices,
+            [
+                "$GPGGA,data*6A",
+                "$GPZDA,123",
+                "!AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5F"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences_mixed() {
+        let buffer =
+            "noise\r\n$GPGGA,trunc$GPGGA,data*6A$GPZDA*1\n\n\nö garbage*ä€x\r\n*\r\n$GPGSV,par";
+
+        let slices: Vec<_> = split_sentences(buffer).collect();
+        assert_eq!(
+            slices,
+            [
+                "noise",
+                "$GPGGA,trunc",
+                "$GPGGA,data*6A",
+                "$GPZDA*1",
+                "ö garbage*ä€",
+                "x",
+                "*",
+                "$GPGSV,par"
+            ]
+        );
+
+        assert_eq!(split_sentences("\r\n\n").next(), None);
+        assert_eq!(split_sentences("").next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_normalize() {
+        let log = "\0noise\r\n$GPGGA,trunc$GPGGA,data*6a\r\n\n$GPZDA,123\n\
+                   !AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5f$GPGGA,data*00\r\n\
+                   $GPGGA,é\r\n$GPDBT,1.0,f,0.3,M,0.2,F*22";
+
+        let sentences: Vec<_> = normalize(log).collect();
+        assert_eq!(
+            sentences,
+            [
+                "$GPGGA,trunc*04\r\n",
+                "$GPGGA,data*6A\r\n",
+                "$GPZDA,123*54\r\n",
+                "!AIVDM,1,1,,A,15M67FC000G?ufbE`FepT@3n00Sa,0*5F\r\n",
+            ]
+        );
+
+        assert_eq!(normalize("").next(), None);
+    }
+
+    #[test]
+    fn test_sentences_partial() {
+        let mut iter = sentences("$GPGGA,data*6A\r\n$GPGGA,da");
+        assert_eq!(iter.next(), Some("$GPGGA,data*6A\r\n"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), "$GPGGA,da");
+
+        // The carriage return alone does not terminate a sentence
+        let mut iter = sentences("noise$GPGGA,data*6A\r");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), "$GPGGA,data*6A\r");
+
+        let mut iter = sentences("noise only");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), "");
+
+        assert_eq!(sentences("").next(), None);
+    }
+}
@@ -0,0 +1,1107 @@
+#![cfg(feature = "derive")]
+
+use nmea0183_parser::{IResult, NmeaEncode, NmeaParse};
+use nom::character::complete::char;
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(separator(char(';')))]
+struct SemicolonSeparated {
+    a: u8,
+    b: Option<f32>,
+    c: u16,
+}
+
+#[test]
+fn test_struct_separator() {
+    let result: IResult<_, _> = SemicolonSeparated::parse("1;2.5;3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            SemicolonSeparated {
+                a: 1,
+                b: Some(2.5),
+                c: 3
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = SemicolonSeparated::parse("1;;3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            SemicolonSeparated {
+                a: 1,
+                b: None,
+                c: 3
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = SemicolonSeparated::parse("1,2.5,3");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(separator(char('*')))]
+struct Inner(u8, u8);
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Outer {
+    id: u8,
+    inner: Inner,
+    trailer: char,
+}
+
+#[test]
+fn test_nested_struct_separator() {
+    let result: IResult<_, _> = Outer::parse("7,1*2,X");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Outer {
+                id: 7,
+                inner: Inner(1, 2),
+                trailer: 'X'
+            }
+        ))
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(selector(u8::parse), separator(char(':')))]
+enum ColonSeparated {
+    #[nmea(selector(0))]
+    TypeA { id: u8, value: Option<u16> },
+    #[nmea(selector(1))]
+    TypeB(f32),
+    #[nmea(selector(2))]
+    TypeC,
+}
+
+#[test]
+fn test_enum_separator() {
+    let result: IResult<_, _> = ColonSeparated::parse("0:42:100");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            ColonSeparated::TypeA {
+                id: 42,
+                value: Some(100)
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = ColonSeparated::parse("0:42:");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            ColonSeparated::TypeA {
+                id: 42,
+                value: None
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = ColonSeparated::parse("1:2.5");
+    assert_eq!(result, Ok(("", ColonSeparated::TypeB(2.5))));
+
+    let result: IResult<_, _> = ColonSeparated::parse("2");
+    assert_eq!(result, Ok(("", ColonSeparated::TypeC)));
+
+    let result: IResult<_, _> = ColonSeparated::parse("1,2.5");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(selector(nom::bytes::complete::take(1u8)))]
+enum VariantSeparated {
+    #[nmea(selector("#"), separator(nom::combinator::success(())))]
+    Hash(u16),
+    #[nmea(selector("S"), separator(char(';')))]
+    Semicolon { id: u8, value: u16 },
+    #[nmea(selector("A"))]
+    TypeA(u8),
+}
+
+#[test]
+fn test_variant_separator() {
+    let result: IResult<_, _> = VariantSeparated::parse("#42");
+    assert_eq!(result, Ok(("", VariantSeparated::Hash(42))));
+
+    let result: IResult<_, _> = VariantSeparated::parse("S;1;2");
+    assert_eq!(
+        result,
+        Ok(("", VariantSeparated::Semicolon { id: 1, value: 2 }))
+    );
+
+    let result: IResult<_, _> = VariantSeparated::parse("S,1,2");
+    assert!(result.is_err());
+
+    // Other variants keep the separator of the enum
+    let result: IResult<_, _> = VariantSeparated::parse("A,7");
+    assert_eq!(result, Ok(("", VariantSeparated::TypeA(7))));
+
+    let result: IResult<_, _> = VariantSeparated::parse("A;7");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct RemainingFields {
+    a: u8,
+    #[nmea(if_remaining_fields(2))]
+    b: Option<f32>,
+    c: char,
+    #[nmea(if_remaining_fields(1))]
+    trailer: Option<char>,
+}
+
+#[test]
+fn test_if_remaining_fields() {
+    let result: IResult<_, _> = RemainingFields::parse("1,C");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFields {
+                a: 1,
+                b: None,
+                c: 'C',
+                trailer: None
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = RemainingFields::parse("1,2.5,C,T");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFields {
+                a: 1,
+                b: Some(2.5),
+                c: 'C',
+                trailer: Some('T')
+            }
+        ))
+    );
+
+    // Present but empty fields are `None` as well
+    let result: IResult<_, _> = RemainingFields::parse("1,,C,");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFields {
+                a: 1,
+                b: None,
+                c: 'C',
+                trailer: None
+            }
+        ))
+    );
+
+    // `b` is parsed as soon as two fields remain, even if the trailer is missing
+    let result: IResult<_, _> = RemainingFields::parse("1,2.5,C");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFields {
+                a: 1,
+                b: Some(2.5),
+                c: 'C',
+                trailer: None
+            }
+        ))
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(separator(char(';')))]
+struct RemainingFieldsSeparated {
+    a: u8,
+    #[nmea(if_remaining_fields(2))]
+    b: Option<f32>,
+    c: char,
+}
+
+#[test]
+fn test_if_remaining_fields_separator() {
+    // The remaining fields are counted by the configured separator, not by commas
+    let result: IResult<_, _> = RemainingFieldsSeparated::parse("1;2.5;C");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFieldsSeparated {
+                a: 1,
+                b: Some(2.5),
+                c: 'C'
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = RemainingFieldsSeparated::parse("1;C");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFieldsSeparated {
+                a: 1,
+                b: None,
+                c: 'C'
+            }
+        ))
+    );
+
+    // Commas do not count as separators
+    let result: IResult<_, _> = RemainingFieldsSeparated::parse("1;,");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            RemainingFieldsSeparated {
+                a: 1,
+                b: None,
+                c: ','
+            }
+        ))
+    );
+}
+
+mod count {
+    // `NmeaParse` trait methods must resolve without the trait being in scope.
+    use nmea0183_parser::IResult;
+
+    #[derive(Debug, PartialEq, nmea0183_parser::NmeaParse)]
+    struct Counted {
+        count: u8,
+        #[nmea(count(count))]
+        values: Vec<Option<u16>>,
+        trailer: char,
+    }
+
+    #[test]
+    fn test_count() {
+        let result: IResult<_, _> = nmea0183_parser::NmeaParse::parse("3,1,,3,X");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                Counted {
+                    count: 3,
+                    values: vec![Some(1), None, Some(3)],
+                    trailer: 'X',
+                }
+            ))
+        );
+
+        let result: IResult<_, _> = nmea0183_parser::NmeaParse::parse("0,X");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                Counted {
+                    count: 0,
+                    values: vec![],
+                    trailer: 'X',
+                }
+            ))
+        );
+
+        // Fewer elements than announced
+        let result: IResult<&str, Counted> = nmea0183_parser::NmeaParse::parse("3,1,2");
+        assert!(result.is_err());
+    }
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Peeked {
+    id: u8,
+    #[nmea(peek(nom::character::complete::anychar))]
+    next: char,
+    #[nmea(cond(next == 'X'))]
+    marker: Option<char>,
+    value: u16,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct PeekedFirst {
+    #[nmea(peek(nom::character::complete::anychar))]
+    first: char,
+    value: u16,
+}
+
+#[test]
+fn test_peek() {
+    let result: IResult<_, _> = Peeked::parse("1,X,5");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Peeked {
+                id: 1,
+                next: 'X',
+                marker: Some('X'),
+                value: 5
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Peeked::parse("1,5");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Peeked {
+                id: 1,
+                next: '5',
+                marker: None,
+                value: 5
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = PeekedFirst::parse("42");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            PeekedFirst {
+                first: '4',
+                value: 42
+            }
+        ))
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Verified {
+    #[nmea(verify(|h: &f32| *h < 360.0))]
+    heading: f32,
+    #[nmea(verify(|v: &Option<u8>| v.is_none_or(|v| v <= 9)))]
+    digit: Option<u8>,
+}
+
+#[test]
+fn test_verify() {
+    let result: IResult<_, _> = Verified::parse("123.5,7");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Verified {
+                heading: 123.5,
+                digit: Some(7)
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Verified::parse("0,");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Verified {
+                heading: 0.0,
+                digit: None
+            }
+        ))
+    );
+
+    let result: IResult<&str, Verified> = Verified::parse("361.0,7");
+    assert!(result.is_err());
+
+    let result: IResult<&str, Verified> = Verified::parse("123.5,10");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Bits {
+    #[nmea(parse_as(u16), bits(0..3))]
+    low: u8,
+    #[nmea(bits(4..=7), parse_as(u16))]
+    high: u16,
+}
+
+#[test]
+fn test_bits() {
+    let result: IResult<_, _> = Bits::parse("13,242");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Bits {
+                low: 0b101,
+                high: 0b1111
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Bits::parse("65535,15");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Bits {
+                low: 0b111,
+                high: 0
+            }
+        ))
+    );
+
+    let result: IResult<&str, Bits> = Bits::parse("-1,0");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct FullWidthBits {
+    #[nmea(parse_as(u16), bits(0..16))]
+    half_open: u16,
+    #[nmea(parse_as(u32), bits(0..=31))]
+    closed: u32,
+}
+
+#[test]
+fn test_bits_full_width() {
+    let result: IResult<_, _> = FullWidthBits::parse("65535,4294967295");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            FullWidthBits {
+                half_open: u16::MAX,
+                closed: u32::MAX
+            }
+        ))
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct FixedWidth {
+    #[nmea(width(2))]
+    day: u8,
+    #[nmea(width(2))]
+    month: Option<u8>,
+    #[nmea(width(4), verify(|year: &u16| *year > 0))]
+    year: u16,
+}
+
+#[test]
+fn test_width() {
+    let result: IResult<_, _> = FixedWidth::parse("07,03,2024");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            FixedWidth {
+                day: 7,
+                month: Some(3),
+                year: 2024
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = FixedWidth::parse("00,00,0099");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            FixedWidth {
+                day: 0,
+                month: Some(0),
+                year: 99
+            }
+        ))
+    );
+
+    // Fields must be exactly as wide as declared
+    let result: IResult<&str, FixedWidth> = FixedWidth::parse("7,03,2024");
+    assert!(result.is_err());
+
+    let result: IResult<&str, FixedWidth> = FixedWidth::parse("07,03,024");
+    assert!(result.is_err());
+
+    // The following attributes apply to the taken characters
+    let result: IResult<&str, FixedWidth> = FixedWidth::parse("07,03,0000");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(collect_errors)]
+struct Collected {
+    id: u8,
+    value: Option<u16>,
+    flag: char,
+    count: u8,
+}
+
+#[test]
+fn test_collect_errors() {
+    let result: IResult<_, _> = Collected::parse_collecting_errors("1,2,A,3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            (
+                Collected {
+                    id: 1,
+                    value: Some(2),
+                    flag: 'A',
+                    count: 3
+                },
+                vec![]
+            )
+        ))
+    );
+
+    let result: IResult<_, _> = Collected::parse_collecting_errors("1,xyz,A,300");
+    let (rest, (collected, errors)) = result.unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(
+        collected,
+        Collected {
+            id: 1,
+            value: None,
+            flag: 'A',
+            count: 0
+        }
+    );
+    let fields: Vec<_> = errors.iter().map(|(field, _)| *field).collect();
+    assert_eq!(fields, ["value", "count"]);
+
+    // The regular parser still stops at the first error
+    let result: IResult<&str, Collected> = Collected::parse("1,xyz,A,300");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(selector(nom::character::complete::anychar))]
+enum MultiSelector {
+    #[nmea(selector('0'))]
+    NoFix,
+    #[nmea(selector('1' | '2'))]
+    Fix(u8),
+    #[nmea(selector('3'..='5'))]
+    Rtk,
+    #[nmea(selector(c if c.is_ascii_alphabetic()))]
+    Other,
+}
+
+#[test]
+fn test_enum_selector_patterns() {
+    let result: IResult<_, _> = MultiSelector::parse("0");
+    assert_eq!(result, Ok(("", MultiSelector::NoFix)));
+
+    for input in ["1,7", "2,7"] {
+        let result: IResult<_, _> = MultiSelector::parse(input);
+        assert_eq!(result, Ok(("", MultiSelector::Fix(7))), "{input:?}");
+    }
+
+    for input in ["3", "4", "5"] {
+        let result: IResult<_, _> = MultiSelector::parse(input);
+        assert_eq!(result, Ok(("", MultiSelector::Rtk)), "{input:?}");
+    }
+
+    let result: IResult<_, _> = MultiSelector::parse("X");
+    assert_eq!(result, Ok(("", MultiSelector::Other)));
+
+    let result: IResult<&str, MultiSelector> = MultiSelector::parse("6");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(selector(nom::character::complete::one_of("ABT")))]
+enum Keyed {
+    #[nmea(selector('A' | 'B'))]
+    Letter(#[nmea(from_selector)] char, u8),
+    #[nmea(selector('T'))]
+    Tagged {
+        value: Option<f32>,
+        #[nmea(map(|key: char| key.is_ascii_uppercase()), from_selector)]
+        uppercase: bool,
+    },
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(selector(u8::parse))]
+enum Coded {
+    #[nmea(selector(0))]
+    Zero,
+    #[nmea(selector(_))]
+    Other {
+        #[nmea(from_selector)]
+        code: u8,
+        value: Option<f32>,
+    },
+}
+
+#[test]
+fn test_from_selector() {
+    let result: IResult<_, _> = Keyed::parse("B,7");
+    assert_eq!(result, Ok(("", Keyed::Letter('B', 7))));
+
+    let result: IResult<_, _> = Keyed::parse("T,1.5");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Keyed::Tagged {
+                value: Some(1.5),
+                uppercase: true,
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Coded::parse("0");
+    assert_eq!(result, Ok(("", Coded::Zero)));
+
+    let result: IResult<_, _> = Coded::parse("42,2.5");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Coded::Other {
+                code: 42,
+                value: Some(2.5),
+            }
+        ))
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode)]
+struct Encoded {
+    id: u8,
+    value: Option<f32>,
+    flag: bool,
+    #[nmea(ignore)]
+    computed: u32,
+    mode: Mode,
+    trailer: Option<char>,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode, selector(nom::character::complete::anychar))]
+enum Mode {
+    #[nmea(selector('A'))]
+    Auto,
+    #[nmea(selector('M'))]
+    Manual(u16, Option<u8>),
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode, selector(nom::bytes::complete::take(3u8)))]
+enum Named {
+    #[nmea(selector("ABC"))]
+    Abc { value: i8 },
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode)]
+struct EncodedTuple(u8, Vec<u8>);
+
+#[test]
+fn test_encode() {
+    let value = Encoded {
+        id: 1,
+        value: Some(2.5),
+        flag: true,
+        computed: 0,
+        mode: Mode::Manual(7, None),
+        trailer: None,
+    };
+    assert_eq!(value.encode(), "1,2.5,1,M,7,,");
+
+    let value = Encoded {
+        id: 1,
+        value: None,
+        flag: false,
+        computed: 0,
+        mode: Mode::Auto,
+        trailer: Some('X'),
+    };
+    assert_eq!(value.encode(), "1,,0,A,X");
+
+    assert_eq!(Named::Abc { value: -5 }.encode(), "ABC,-5");
+    assert_eq!(EncodedTuple(3, vec![4, 5]).encode(), "3,4,5");
+}
+
+#[test]
+fn test_encode_round_trip() {
+    for input in ["1,2.5,1,M,7,,", "1,,0,A,X", "9,0.125,1,M,65535,3,"] {
+        let result: IResult<_, _> = Encoded::parse(input);
+        let (_, value) = result.unwrap();
+        assert_eq!(value.encode(), input);
+    }
+
+    let result: IResult<_, _> = Named::parse("ABC,-5");
+    assert_eq!(result.unwrap().1.encode(), "ABC,-5");
+}
+
+fn xor_checksum(content: &str) -> u8 {
+    content.bytes().fold(0, |acc, byte| acc ^ byte)
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(inner_checksum(xor_checksum))]
+struct InnerChecksum {
+    id: u8,
+    flag: char,
+    checksum: u8,
+}
+
+#[test]
+fn test_inner_checksum() {
+    // '1' ^ '2' ^ ',' ^ 'A' == 110
+    let result: IResult<_, _> = InnerChecksum::parse("12,A,110");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            InnerChecksum {
+                id: 12,
+                flag: 'A',
+                checksum: 110
+            }
+        ))
+    );
+
+    let result: IResult<&str, InnerChecksum> = InnerChecksum::parse("12,A,111");
+    assert_eq!(
+        result,
+        Err(nom::Err::Error(nmea0183_parser::Error::ParsingError(
+            nom::error::Error::new(",111", nom::error::ErrorKind::Verify)
+        )))
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode)]
+struct Knots(f32);
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Speeds {
+    over_ground: Knots,
+    through_water: Option<Knots>,
+}
+
+#[test]
+fn test_newtype() {
+    let result: IResult<_, _> = Knots::parse("12.5");
+    assert_eq!(result, Ok(("", Knots(12.5))));
+
+    let result: IResult<_, _> = Knots::parse("12.5,3");
+    assert_eq!(result, Ok((",3", Knots(12.5))));
+
+    let result: IResult<_, _> = Speeds::parse("12.5,3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Speeds {
+                over_ground: Knots(12.5),
+                through_water: Some(Knots(3.0))
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Speeds::parse("12.5,");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Speeds {
+                over_ground: Knots(12.5),
+                through_water: None
+            }
+        ))
+    );
+
+    assert_eq!(Knots(12.5).encode(), "12.5");
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode)]
+struct Wrapper<T> {
+    a: u8,
+    b: Option<T>,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Composite<T> {
+    list: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Fixed<T> {
+    values: [T; 2],
+}
+
+#[test]
+fn test_generic_composite_fields() {
+    let result: IResult<_, _> = Wrapper::<f32>::parse("1,2.5");
+    assert_eq!(result, Ok(("", Wrapper { a: 1, b: Some(2.5) })));
+
+    let result: IResult<_, _> = Wrapper::<Knots>::parse("1,");
+    assert_eq!(result, Ok(("", Wrapper { a: 1, b: None })));
+
+    assert_eq!(Wrapper { a: 1, b: Some('A') }.encode(), "1,A");
+
+    let result: IResult<_, _> = Composite::<u8>::parse("1,2,3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Composite {
+                list: vec![1, 2, 3]
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Fixed::<i8>::parse("-1,2");
+    assert_eq!(result, Ok(("", Fixed { values: [-1, 2] })));
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Resilient {
+    id: u8,
+    speed: Result<f32, nom::error::ErrorKind>,
+    heading: Result<Option<u16>, nom::error::ErrorKind>,
+    mode: char,
+}
+
+#[test]
+fn test_recovered_fields() {
+    let result: IResult<_, _> = Resilient::parse("1,12.5,270,A");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Resilient {
+                id: 1,
+                speed: Ok(12.5),
+                heading: Ok(Some(270)),
+                mode: 'A'
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Resilient::parse("1,fast,27O,A");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Resilient {
+                id: 1,
+                speed: Err(nom::error::ErrorKind::Float),
+                heading: Err(nom::error::ErrorKind::Verify),
+                mode: 'A'
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Resilient::parse("1,,,A");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Resilient {
+                id: 1,
+                speed: Err(nom::error::ErrorKind::Float),
+                heading: Ok(None),
+                mode: 'A'
+            }
+        ))
+    );
+}
+
+/// Neither `Copy` nor `Default`
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Reading {
+    value: u8,
+    unit: char,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Readings {
+    id: u8,
+    readings: [Reading; 2],
+}
+
+#[test]
+fn test_array_of_non_copy_struct() {
+    let result: IResult<_, _> = Readings::parse("7,1,A,2,B");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Readings {
+                id: 7,
+                readings: [
+                    Reading {
+                        value: 1,
+                        unit: 'A'
+                    },
+                    Reading {
+                        value: 2,
+                        unit: 'B'
+                    },
+                ],
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = <[Reading; 2]>::parse("1,A,2,B,3,C");
+    assert!(matches!(result, Ok((",3,C", [_, _]))));
+
+    let result: IResult<_, Readings> = Readings::parse("7,1,A");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode, selector_ref)]
+enum VariableSelector {
+    #[nmea(selector("VDM"))]
+    Vdm(u8),
+    #[nmea(selector("GRME"))]
+    Grme(Option<f32>),
+    #[nmea(selector("Z"))]
+    Z,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(selector_ref, separator(char(':')))]
+enum VariableSelectorSeparator {
+    #[nmea(selector("AB"))]
+    Ab(u8),
+    #[nmea(selector("ABC"))]
+    Abc(u8),
+}
+
+#[test]
+fn test_selector_ref() {
+    let result: IResult<_, _> = VariableSelector::parse("VDM,7");
+    assert_eq!(result, Ok(("", VariableSelector::Vdm(7))));
+
+    let result: IResult<_, _> = VariableSelector::parse("GRME,15.8");
+    assert_eq!(result, Ok(("", VariableSelector::Grme(Some(15.8)))));
+
+    let result: IResult<_, _> = VariableSelector::parse("Z");
+    assert_eq!(result, Ok(("", VariableSelector::Z)));
+
+    // A selector is never matched by its prefix
+    for input in ["VD,7", "VDMX,7", "GRM,15.8", ",7", ""] {
+        let result: IResult<_, VariableSelector> = VariableSelector::parse(input);
+        assert!(result.is_err(), "{input:?}");
+    }
+
+    assert_eq!(VariableSelector::Grme(Some(1.5)).encode(), "GRME,1.5");
+
+    let result: IResult<_, _> = VariableSelectorSeparator::parse("AB:1");
+    assert_eq!(result, Ok(("", VariableSelectorSeparator::Ab(1))));
+
+    let result: IResult<_, _> = VariableSelectorSeparator::parse("ABC:2");
+    assert_eq!(result, Ok(("", VariableSelectorSeparator::Abc(2))));
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode, selector(u8::parse))]
+enum PubxMessage {
+    #[nmea(selector(0))]
+    Position { latitude: Option<f64>, status: char },
+    #[nmea(selector(4))]
+    Time(Option<u32>),
+    #[nmea(selector(41))]
+    Config,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode, selector_ref)]
+enum Manufacturer {
+    #[nmea(selector("PUBX"))]
+    Ublox(PubxMessage),
+    #[nmea(selector("PGRM"))]
+    Garmin(Option<u8>, u8),
+}
+
+#[test]
+fn test_nested_enum_selectors() {
+    let cases = [
+        (
+            "PUBX,00,4807.0,A",
+            Manufacturer::Ublox(PubxMessage::Position {
+                latitude: Some(4807.0),
+                status: 'A',
+            }),
+        ),
+        (
+            "PUBX,00,,A",
+            Manufacturer::Ublox(PubxMessage::Position {
+                latitude: None,
+                status: 'A',
+            }),
+        ),
+        (
+            "PUBX,04,123",
+            Manufacturer::Ublox(PubxMessage::Time(Some(123))),
+        ),
+        ("PUBX,04,", Manufacturer::Ublox(PubxMessage::Time(None))),
+        ("PUBX,41", Manufacturer::Ublox(PubxMessage::Config)),
+        ("PGRM,,3", Manufacturer::Garmin(None, 3)),
+    ];
+
+    for (input, expected) in cases {
+        let result: IResult<_, _> = Manufacturer::parse(input);
+        assert_eq!(result, Ok(("", expected)), "{input:?}");
+    }
+
+    // The inner selector is not preceded by a second separator
+    for input in ["PUBX,,00,,A", "PUBX00,,A", "PUBX,99,1"] {
+        let result: IResult<_, Manufacturer> = Manufacturer::parse(input);
+        assert!(result.is_err(), "{input:?}");
+    }
+
+    // Trailing input after the inner enum is left to the caller
+    let result: IResult<_, _> = Manufacturer::parse("PUBX,41,X");
+    assert_eq!(result, Ok((",X", Manufacturer::Ublox(PubxMessage::Config))));
+
+    assert_eq!(
+        Manufacturer::Ublox(PubxMessage::Time(Some(123))).encode(),
+        "PUBX,4,123"
+    );
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct VecBeforeTrailer {
+    id: u8,
+    #[nmea(parser(nmea0183_parser::parse_until(nom::sequence::preceded(
+        char(','),
+        nom::character::complete::one_of("AV")
+    ))))]
+    values: Vec<u16>,
+    mode: char,
+    count: u8,
+}
+
+#[test]
+fn test_vec_before_trailer() {
+    let result: IResult<_, _> = VecBeforeTrailer::parse("1,10,20,30,A,3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            VecBeforeTrailer {
+                id: 1,
+                values: vec![10, 20, 30],
+                mode: 'A',
+                count: 3,
+            }
+        ))
+    );
+}
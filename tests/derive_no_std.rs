@@ -0,0 +1,59 @@
+#![cfg(all(feature = "alloc", feature = "derive"))]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use nmea0183_parser::{IResult, NmeaEncode, NmeaParse};
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode)]
+struct Encoded {
+    id: u8,
+    value: Option<f32>,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(encode, selector(nom::character::complete::anychar))]
+enum Mode {
+    #[nmea(selector('A'))]
+    Auto,
+    #[nmea(selector('M'))]
+    Manual(u16),
+}
+
+#[test]
+fn test_encode_no_std() {
+    let result: IResult<_, _> = Encoded::parse("1,2.5,M,7");
+    let (_, value) = result.unwrap();
+    assert_eq!(
+        value,
+        Encoded {
+            id: 1,
+            value: Some(2.5),
+            mode: Mode::Manual(7),
+        }
+    );
+    assert_eq!(value.encode(), "1,2.5,M,7");
+
+    let values = vec![Some(1u8), None];
+    assert_eq!(values.encode(), "1,");
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+#[nmea(collect_errors)]
+struct Collected {
+    id: u8,
+    value: Option<u16>,
+}
+
+#[test]
+fn test_collect_errors_no_std() {
+    let result: IResult<_, _> = Collected::parse_collecting_errors("1,xyz");
+    let (rest, (collected, errors)) = result.unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(collected, Collected { id: 1, value: None });
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "value");
+}
@@ -0,0 +1,7 @@
+#![cfg(feature = "derive")]
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
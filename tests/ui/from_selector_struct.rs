@@ -0,0 +1,10 @@
+use nmea0183_parser::NmeaParse;
+
+#[derive(NmeaParse)]
+struct Data {
+    #[nmea(from_selector)]
+    key: char,
+    value: u8,
+}
+
+fn main() {}
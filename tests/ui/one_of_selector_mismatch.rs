@@ -0,0 +1,12 @@
+use nmea0183_parser::NmeaParse;
+
+#[derive(NmeaParse)]
+#[nmea(selector(nom::character::complete::one_of("AV")))]
+enum Status {
+    #[nmea(selector('A'))]
+    Valid,
+    #[nmea(selector('X'))]
+    Invalid,
+}
+
+fn main() {}
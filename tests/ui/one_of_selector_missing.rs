@@ -0,0 +1,12 @@
+use nmea0183_parser::NmeaParse;
+
+#[derive(NmeaParse)]
+#[nmea(selector(nom::character::complete::one_of("AVN")))]
+enum Status {
+    #[nmea(selector('A'))]
+    Valid,
+    #[nmea(selector('V'))]
+    Invalid,
+}
+
+fn main() {}